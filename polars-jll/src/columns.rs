@@ -1,7 +1,7 @@
 use polars::prelude::*;
-use jlrs::{data::managed::{string::StringRet, value::typed::TypedValue}, prelude::*};
+use jlrs::{data::managed::{ccall_ref::CCallRef, string::StringRet, value::{typed::TypedValue, ValueRet}}, inline_static_ref, prelude::*, weak_handle};
 
-use crate::{errors::PolarsJlError, polars_value_type_t, utils::{leak_string, leak_value, CCallRefExt}, values::{polars_value_t, AnyValueRet}, ValueTypeRef, ValueTypeRet};
+use crate::{errors::{catch_panic, PolarsJlError, PolarsJlResult}, polars_dataframe_t, polars_value_type_t, utils::{from_bits, jl_binary_vector, jl_string_vector, jl_vector, leak_string, leak_value, CCallRefExt, JuliaValueExt}, value_types::{time_unit_as_str, time_unit_from_str}, values::{jl_datetime_vector, jl_decimal_vector, polars_value_t, AnyValueRef, AnyValueRet}, DataFrameRet, ValueTypeRef, ValueTypeRet};
 
 
 #[derive(Debug, OpaqueType)]
@@ -17,33 +17,642 @@ pub type ColumnValue<'scope, 'data> = TypedValue<'scope, 'data, polars_column_t>
 
 impl polars_column_t {
   pub fn new_empty(name: JuliaString, dtype: ValueTypeRef) -> JlrsResult<ColumnRet> {
-    let name = name.as_str()?.to_string();
-    let dtype = dtype.tracked_map(|i| i.inner.clone())?;
-    Ok(leak_value(Self { inner: Column::new_empty(name.into(), &dtype) }))
+    catch_panic("polars_column_new_empty", || {
+      let name = name.as_str()?.to_string();
+      let dtype = dtype.tracked_map(|i| i.inner.clone())?;
+      Ok(leak_value(Self { inner: Column::new_empty(name.into(), &dtype) }))
+    })
   }
 
-  pub fn len(&self) -> usize {
-    self.inner.len()
+  /// Builds a column from the raw bits of a Julia vector with a fixed width element type,
+  /// `validity` is either empty or holds one flag per value.
+  pub fn from_bits(name: JuliaString, dtype: ValueTypeRef, data: TypedVector<u8>, validity: TypedVector<bool>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_from_bits", || {
+      let name: PlSmallStr = name.as_str()?.into();
+      let dtype = dtype.tracked_map(|i| i.inner.clone())?;
+      let data = unsafe { data.bits_data() };
+      let validity = unsafe { validity.bits_data() };
+      let (data, validity) = (data.as_slice(), validity.as_slice());
+      let s = match &dtype {
+        DataType::Boolean => {
+          check_bits(data, 1, validity)?;
+          let values = from_bits::<u8>(data).map(|v| v != 0);
+          if validity.is_empty() {
+            BooleanChunked::from_iter_values(name, values).into_series()
+          } else {
+            BooleanChunked::from_iter_options(name, values.zip(validity).map(|(v, ok)| ok.then_some(v))).into_series()
+          }
+        },
+        #[cfg(feature = "dtype-i8")]
+        DataType::Int8 => numeric_from_bits::<Int8Type>(name, data, validity)?.into_series(),
+        #[cfg(feature = "dtype-i16")]
+        DataType::Int16 => numeric_from_bits::<Int16Type>(name, data, validity)?.into_series(),
+        DataType::Int32 => numeric_from_bits::<Int32Type>(name, data, validity)?.into_series(),
+        DataType::Int64 => numeric_from_bits::<Int64Type>(name, data, validity)?.into_series(),
+        #[cfg(feature = "dtype-i128")]
+        DataType::Int128 => numeric_from_bits::<Int128Type>(name, data, validity)?.into_series(),
+        #[cfg(feature = "dtype-u8")]
+        DataType::UInt8 => numeric_from_bits::<UInt8Type>(name, data, validity)?.into_series(),
+        #[cfg(feature = "dtype-u16")]
+        DataType::UInt16 => numeric_from_bits::<UInt16Type>(name, data, validity)?.into_series(),
+        DataType::UInt32 => numeric_from_bits::<UInt32Type>(name, data, validity)?.into_series(),
+        DataType::UInt64 => numeric_from_bits::<UInt64Type>(name, data, validity)?.into_series(),
+        DataType::Float32 => numeric_from_bits::<Float32Type>(name, data, validity)?.into_series(),
+        DataType::Float64 => numeric_from_bits::<Float64Type>(name, data, validity)?.into_series(),
+        // temporal values are passed as their physical integers
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => numeric_from_bits::<Int32Type>(name, data, validity)?.into_date().into_series(),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, tz) => numeric_from_bits::<Int64Type>(name, data, validity)?.into_datetime(*tu, tz.clone()).into_series(),
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(tu) => numeric_from_bits::<Int64Type>(name, data, validity)?.into_duration(*tu).into_series(),
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => numeric_from_bits::<Int64Type>(name, data, validity)?.into_time().into_series(),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(precision, scale) => {
+          // the julia side passes the Int128 mantissas
+          numeric_from_bits::<Int128Type>(name, data, validity)?
+            .into_decimal(*precision, scale.unwrap_or(0))?
+            .into_series()
+        },
+        dt => return Err(PolarsJlError::UnsupportedDataType(dt.to_string()))?,
+      };
+      Ok(leak_value(Self { inner: s.into_column() }))
+    })
   }
 
-  pub fn null_count(&self) -> usize {
-    self.inner.null_count()
+  /// A List column, row i holds `values` from `offsets[i]` up to `offsets[i + 1]` or is
+  /// null if `validity[i]` is false, `validity` is either empty or holds one flag per row.
+  pub fn from_list(name: JuliaString, values: ColumnRef, offsets: TypedVector<i64>, validity: TypedVector<bool>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_from_list", || {
+      let name: PlSmallStr = name.as_str()?.into();
+      let values = values.tracked_map(|c| c.inner.as_materialized_series().clone())?;
+      let offsets = unsafe { offsets.bits_data() };
+      let validity = unsafe { validity.bits_data() };
+      let (offsets, validity) = (offsets.as_slice(), validity.as_slice());
+      check_offsets(offsets, values.len(), validity)?;
+      let n = offsets.len() - 1;
+      let mut builder = get_list_builder(values.dtype(), values.len(), n, name);
+      for (i, w) in offsets.windows(2).enumerate() {
+        match validity.is_empty() || validity[i] {
+          true => builder.append_series(&values.slice(w[0], (w[1] - w[0]) as usize))?,
+          false => builder.append_null(),
+        }
+      }
+      Ok(leak_value(Self { inner: builder.finish().into_series().into_column() }))
+    })
   }
 
-  pub fn dtype(&self) -> ValueTypeRet {
-    leak_value(polars_value_type_t { inner: self.inner.dtype().clone() })
+  /// Builds a String or Binary column from the concatenated bytes of all values,
+  /// `offsets` has `len + 1` entries and `validity` is either empty or holds one flag per value.
+  pub fn from_bytes(name: JuliaString, dtype: ValueTypeRef, data: TypedVector<u8>, offsets: TypedVector<i64>, validity: TypedVector<bool>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_from_bytes", || {
+      let name: PlSmallStr = name.as_str()?.into();
+      let dtype = dtype.tracked_map(|i| i.inner.clone())?;
+      let data = unsafe { data.bits_data() };
+      let offsets = unsafe { offsets.bits_data() };
+      let validity = unsafe { validity.bits_data() };
+      let (data, offsets, validity) = (data.as_slice(), offsets.as_slice(), validity.as_slice());
+      check_offsets(offsets, data.len(), validity)?;
+      let values = offsets.windows(2).enumerate().map(|(i, w)| {
+        (validity.is_empty() || validity[i]).then(|| &data[w[0] as usize..w[1] as usize])
+      });
+      let s = match &dtype {
+        DataType::Binary => BinaryChunked::from_iter_options(name, values).into_series(),
+        DataType::String => {
+          let values = values
+            .map(|v| v.map(std::str::from_utf8).transpose())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| polars_err!(ComputeError: "invalid utf-8 in string column: {}", e))?;
+          StringChunked::from_iter_options(name, values.into_iter()).into_series()
+        },
+        dt => return Err(PolarsJlError::UnsupportedDataType(dt.to_string()))?,
+      };
+      Ok(leak_value(Self { inner: s.into_column() }))
+    })
   }
 
-  pub fn name(&self) -> StringRet {
-    leak_string(self.inner.name().as_str())
+  pub fn len(&self) -> JlrsResult<usize> {
+    catch_panic("polars_column_len", || Ok(self.inner.len()))
   }
 
-  pub fn is_null(&self, idx: usize) -> bool {
-    matches!(self.inner.get(idx), Ok(AnyValue::Null))
+  pub fn null_count(&self) -> JlrsResult<usize> {
+    catch_panic("polars_column_null_count", || Ok(self.inner.null_count()))
+  }
+
+  pub fn dtype(&self) -> JlrsResult<ValueTypeRet> {
+    catch_panic("polars_column_dtype", || {
+      Ok(leak_value(polars_value_type_t { inner: self.inner.dtype().clone() }))
+    })
+  }
+
+  pub fn name(&self) -> JlrsResult<StringRet> {
+    catch_panic("polars_column_name", || Ok(leak_string(self.inner.name().as_str())))
+  }
+
+  pub fn is_null(&self, idx: usize) -> JlrsResult<bool> {
+    catch_panic("polars_column_is_null", || Ok(matches!(self.inner.get(idx), Ok(AnyValue::Null))))
   }
 
   pub fn get(&self, idx: usize) -> JlrsResult<AnyValueRet> {
-    let v = self.inner.get(idx).map_err(PolarsJlError::from)?;
-    Ok(leak_value(polars_value_t { inner: v.into_static() }))
+    catch_panic("polars_column_get", || {
+      let v = self.inner.get(idx).map_err(PolarsJlError::from)?;
+      Ok(leak_value(polars_value_t { inner: v.into_static() }))
+    })
+  }
+
+  /// Converts the whole column into a Julia vector, nulls become `nothing`.
+  pub fn to_vector(&self) -> JlrsResult<ValueRet> {
+    catch_panic("polars_column_to_vector", || {
+      match weak_handle!() {
+        Ok(handle) => column_to_vector(&handle, &self.inner),
+        Err(_) => Err(PolarsJlError::WeakHandleError("polars_column_t::to_vector"))?,
+      }
+    })
+  }
+
+  /// The offsets of a List or Array column into `list_values`, with `len + 1` entries.
+  pub fn list_offsets(&self) -> JlrsResult<ValueRet> {
+    catch_panic("polars_column_list_offsets", || {
+      match weak_handle!() {
+        Ok(handle) => jl_vector(&handle, "Int64", &list_offsets(&self.inner)?, None),
+        Err(_) => Err(PolarsJlError::WeakHandleError("polars_column_t::list_offsets"))?,
+      }
+    })
+  }
+
+  /// The flattened values of a List or Array column, without copying them.
+  pub fn list_values(&self) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_list_values", || {
+      let values = match self.inner.dtype() {
+        DataType::List(_) => self.inner.list()?.rechunk().get_inner(),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(_, _) => self.inner.array()?.rechunk().get_inner(),
+        dt => return Err(PolarsJlError::UnsupportedDataType(dt.to_string()))?,
+      };
+      Ok(leak_value(Self { inner: values.into_column() }))
+    })
+  }
+
+  /// Elementwise `op` of two columns, a side of length 1 is broadcast. The result keeps the name of `self`.
+  /// op: :add, :sub, :mul, :div, :rem, :and, :or, :eq, :ne, :lt, :le, :gt, :ge
+  pub fn binary(&self, other: ColumnRef, op: CCallRef<Symbol>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_binary", || {
+      let other = other.tracked_map(|c| c.inner.clone())?;
+      let (a, b) = (self.inner.as_materialized_series(), other.as_materialized_series());
+      let s = match op.as_managed()?.as_str()? {
+        "add" => (a + b)?,
+        "sub" => (a - b)?,
+        "mul" => (a * b)?,
+        // true division, like julia's `/`
+        "div" if a.dtype().is_integer() && b.dtype().is_integer() => {
+          (&a.cast(&DataType::Float64)? / &b.cast(&DataType::Float64)?)?
+        },
+        "div" => (a / b)?,
+        "rem" => (a % b)?,
+        "and" => (a.bool()? & b.bool()?).into_series(),
+        "or" => (a.bool()? | b.bool()?).into_series(),
+        "eq" => a.equal(b)?.into_series(),
+        "ne" => a.not_equal(b)?.into_series(),
+        "lt" => a.lt(b)?.into_series(),
+        "le" => a.lt_eq(b)?.into_series(),
+        "gt" => a.gt(b)?.into_series(),
+        "ge" => a.gt_eq(b)?.into_series(),
+        op => return Err(PolarsJlError::OperationError(op.to_string()))?,
+      };
+      Ok(leak_value(Self { inner: s.with_name(self.inner.name().clone()).into_column() }))
+    })
+  }
+
+  /// Reduces the column to one value.
+  /// op: :sum, :mean, :min, :max, :median, :product, :n_unique, :any, :all
+  pub fn reduce(&self, op: CCallRef<Symbol>) -> JlrsResult<AnyValueRet> {
+    catch_panic("polars_column_reduce", || {
+      let s = self.inner.as_materialized_series();
+      let v = match op.as_managed()?.as_str()? {
+        "sum" => s.sum_reduce()?.into_value(),
+        "mean" => s.mean_reduce().into_value(),
+        "min" => s.min_reduce()?.into_value(),
+        "max" => s.max_reduce()?.into_value(),
+        "median" => s.median_reduce()?.into_value(),
+        "product" => s.product()?.into_value(),
+        "n_unique" => AnyValue::UInt64(s.n_unique()? as u64),
+        // nulls are ignored
+        "any" => AnyValue::Boolean(s.bool()?.any()),
+        "all" => AnyValue::Boolean(s.bool()?.all()),
+        op => return Err(PolarsJlError::OperationError(op.to_string()))?,
+      };
+      Ok(leak_value(polars_value_t { inner: v }))
+    })
+  }
+
+  pub fn std(&self, ddof: u8) -> JlrsResult<AnyValueRet> {
+    catch_panic("polars_column_std", || {
+      let v = self.inner.as_materialized_series().std_reduce(ddof)?.into_value();
+      Ok(leak_value(polars_value_t { inner: v }))
+    })
+  }
+
+  pub fn var(&self, ddof: u8) -> JlrsResult<AnyValueRet> {
+    catch_panic("polars_column_var", || {
+      let v = self.inner.as_materialized_series().var_reduce(ddof)?.into_value();
+      Ok(leak_value(polars_value_t { inner: v }))
+    })
+  }
+
+  /// method: :nearest, :lower, :higher, :midpoint, :linear, :equiprobable
+  pub fn quantile(&self, quantile: f64, method: CCallRef<Symbol>) -> JlrsResult<AnyValueRet> {
+    catch_panic("polars_column_quantile", || {
+      let method = match method.as_managed()?.as_str()? {
+        "nearest" => QuantileMethod::Nearest,
+        "lower" => QuantileMethod::Lower,
+        "higher" => QuantileMethod::Higher,
+        "midpoint" => QuantileMethod::Midpoint,
+        "linear" => QuantileMethod::Linear,
+        "equiprobable" => QuantileMethod::Equiprobable,
+        m => return Err(PolarsJlError::OperationError(format!("quantile method {m}")))?,
+      };
+      let v = self.inner.as_materialized_series().quantile_reduce(quantile, method)?.into_value();
+      Ok(leak_value(polars_value_t { inner: v }))
+    })
+  }
+
+  /// Bool mask of the values that occur more than once.
+  pub fn is_duplicated(&self) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_is_duplicated", || {
+      let mask = is_duplicated(self.inner.as_materialized_series())?;
+      Ok(leak_value(Self { inner: mask.with_name(self.inner.name().clone()).into_column() }))
+    })
+  }
+
+  /// Bool mask of the values that occur exactly once.
+  pub fn is_unique(&self) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_is_unique", || {
+      let mask = is_unique(self.inner.as_materialized_series())?;
+      Ok(leak_value(Self { inner: mask.with_name(self.inner.name().clone()).into_column() }))
+    })
+  }
+
+  /// A frame of the unique values and their counts in a column `name`, sorted by descending
+  /// count if `sort`. Counts are fractions of the column length if `normalize`.
+  pub fn value_counts(&self, sort: bool, normalize: bool, name: JuliaString) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_column_value_counts", || {
+      let s = self.inner.as_materialized_series();
+      let df = s.value_counts(sort, true, name.as_str()?.into(), normalize)?;
+      Ok(leak_value(polars_dataframe_t { inner: df }))
+    })
+  }
+
+  /// Replaces the nulls by `value`, the column is cast to the supertype if needed.
+  pub fn fill_null(&self, value: AnyValueRef) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_fill_null", || {
+      let value = value.tracked_map(|v| v.inner.clone())?;
+      let value = Scalar::new(value.dtype(), value).into_column(PlSmallStr::EMPTY);
+      Ok(leak_value(Self { inner: self.inner.zip_with(&self.inner.is_not_null(), &value)? }))
+    })
+  }
+
+  /// Replaces the nulls following `strategy`, `limit` bounds the number of consecutive
+  /// nulls filled by :forward and :backward and is `nothing` otherwise.
+  /// strategy: :forward, :backward, :mean, :min, :max, :zero, :one
+  pub fn fill_null_with_strategy(&self, strategy: CCallRef<Symbol>, limit: CCallRef<Value>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_fill_null_with_strategy", || {
+      let limit = match limit.as_value()?.is::<Nothing>() {
+        true => None,
+        false => Some(limit.as_unbox::<u64>()? as IdxSize),
+      };
+      let strategy = match strategy.as_managed()?.as_str()? {
+        "forward" => FillNullStrategy::Forward(limit),
+        "backward" => FillNullStrategy::Backward(limit),
+        "mean" => FillNullStrategy::Mean,
+        "min" => FillNullStrategy::Min,
+        "max" => FillNullStrategy::Max,
+        "zero" => FillNullStrategy::Zero,
+        "one" => FillNullStrategy::One,
+        s => return Err(PolarsJlError::OperationError(s.to_string()))?,
+      };
+      Ok(leak_value(Self { inner: self.inner.fill_null(strategy)? }))
+    })
+  }
+
+  /// Replaces the NaN values of a float column by `value`, nulls are kept.
+  pub fn fill_nan(&self, value: AnyValueRef) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_fill_nan", || {
+      if !self.inner.dtype().is_float() {
+        Err(polars_err!(opq = fill_nan, self.inner.dtype()))?
+      }
+      let value = value.tracked_map(|v| v.inner.clone())?;
+      let value = Scalar::new(value.dtype(), value).into_column(PlSmallStr::EMPTY);
+      // nulls are not NaN and stay null
+      let keep = self.inner.as_materialized_series().is_not_nan()?.fill_null_with_values(true)?;
+      Ok(leak_value(Self { inner: self.inner.zip_with(&keep, &value)? }))
+    })
+  }
+
+  /// Bool mask of the NaN values of a float column, null where the column is null.
+  pub fn is_nan(&self) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_is_nan", || {
+      if !self.inner.dtype().is_float() {
+        Err(polars_err!(opq = is_nan, self.inner.dtype()))?
+      }
+      Ok(leak_value(Self { inner: self.inner.is_nan()?.into_column() }))
+    })
+  }
+
+  /// Fills the nulls between two values, leading and trailing nulls are kept.
+  /// method: :linear, :nearest
+  pub fn interpolate(&self, method: CCallRef<Symbol>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_interpolate", || {
+      let method = match method.as_managed()?.as_str()? {
+        "linear" => InterpolationMethod::Linear,
+        "nearest" => InterpolationMethod::Nearest,
+        m => return Err(PolarsJlError::OperationError(m.to_string()))?,
+      };
+      Ok(leak_value(Self { inner: interpolate(self.inner.as_materialized_series(), method).into_column() }))
+    })
+  }
+
+  /// A strict cast fails on values that can't be converted and lists them in the error.
+  pub fn cast(&self, dtype: ValueTypeRef, strict: bool) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_cast", || {
+      let dtype = dtype.tracked_map(|i| i.inner.clone())?;
+      let col = match strict {
+        true => self.inner.strict_cast(&dtype)?,
+        false => self.inner.cast(&dtype)?,
+      };
+      Ok(leak_value(Self { inner: col }))
+    })
+  }
+
+  pub fn cast_time_unit(&self, unit: CCallRef<Symbol>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_cast_time_unit", || {
+      let tu = time_unit_from_str(unit.as_managed()?.as_str()?)?;
+      let s = match self.inner.dtype() {
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, _) => self.inner.datetime()?.cast_time_unit(tu).into_series(),
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(_) => self.inner.duration()?.cast_time_unit(tu).into_series(),
+        dt => return Err(PolarsJlError::UnsupportedDataType(dt.to_string()))?,
+      };
+      Ok(leak_value(Self { inner: s.into_column() }))
+    })
+  }
+
+  /// Keeps the instants and changes the time zone they are shown in.
+  pub fn convert_time_zone(&self, time_zone: JuliaString) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_convert_time_zone", || {
+      let tz = TimeZone::opt_try_new(Some(time_zone.as_str()?)).map_err(PolarsJlError::from)?;
+      let mut ca = self.inner.datetime()?.clone();
+      if ca.time_zone().is_none() {
+        Err(polars_err!(InvalidOperation: "cannot convert a naive datetime, use replace_time_zone first"))?
+      }
+      // values are stored in UTC, so only the dtype changes
+      ca.set_time_zone(tz.unwrap_or(TimeZone::UTC))?;
+      Ok(leak_value(Self { inner: ca.into_series().into_column() }))
+    })
+  }
+
+  /// Keeps the wall time and changes the time zone, `nothing` makes the column naive.
+  pub fn replace_time_zone(&self, time_zone: CCallRef<Value>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_replace_time_zone", || {
+      let tz = match time_zone.as_cast_opt::<JuliaString>()? {
+        Some(tz) => Some(tz.as_str()?.to_string()),
+        None => None,
+      };
+      let tz = TimeZone::opt_try_new(tz).map_err(PolarsJlError::from)?;
+      let ambiguous = StringChunked::from_slice(PlSmallStr::EMPTY, &["raise"]);
+      let ca = replace_time_zone(self.inner.datetime()?, tz.as_ref(), &ambiguous, NonExistent::Raise)?;
+      Ok(leak_value(Self { inner: ca.into_series().into_column() }))
+    })
+  }
+
+  /// The codes and pool of a Categorical or Enum column, see `jl_categorical`.
+  pub fn categorical_parts(&self) -> JlrsResult<ValueRet> {
+    catch_panic("polars_column_categorical_parts", || {
+      match weak_handle!() {
+        #[cfg(feature = "dtype-categorical")]
+        Ok(handle) => jl_categorical(&handle, &self.inner, false),
+        #[cfg(not(feature = "dtype-categorical"))]
+        Ok(_) => Err(PolarsJlError::UnsupportedDataType(self.inner.dtype().to_string()))?,
+        Err(_) => Err(PolarsJlError::WeakHandleError("polars_column_t::categorical_parts"))?,
+      }
+    })
+  }
+
+  pub fn struct_width(&self) -> JlrsResult<usize> {
+    catch_panic("polars_column_struct_width", || {
+      Ok(self.inner.struct_()?.struct_fields().len())
+    })
+  }
+
+  pub fn struct_field(&self, idx: usize) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_struct_field", || {
+      let fields = self.inner.struct_()?.fields_as_series();
+      let field = fields.get(idx).ok_or_else(|| polars_err!(oob = idx, fields.len()))?;
+      Ok(leak_value(Self { inner: field.clone().into_column() }))
+    })
+  }
+}
+
+pub(crate) fn column_to_vector<'scope>(handle: &impl Target<'scope>, col: &Column) -> PolarsJlResult<ValueRet> {
+  match col.dtype() {
+    DataType::Boolean => {
+      let ca = col.bool()?;
+      let values = ca.iter().map(|v| v.unwrap_or_default()).collect::<Vec<_>>();
+      jl_vector(handle, "Bool", &values, validity(ca).as_deref())
+    },
+    #[cfg(feature = "dtype-i8")]
+    DataType::Int8 => numeric_to_vector(handle, "Int8", col.i8()?),
+    #[cfg(feature = "dtype-i16")]
+    DataType::Int16 => numeric_to_vector(handle, "Int16", col.i16()?),
+    DataType::Int32 => numeric_to_vector(handle, "Int32", col.i32()?),
+    DataType::Int64 => numeric_to_vector(handle, "Int64", col.i64()?),
+    #[cfg(feature = "dtype-i128")]
+    DataType::Int128 => numeric_to_vector(handle, "Int128", col.i128()?),
+    #[cfg(feature = "dtype-u8")]
+    DataType::UInt8 => numeric_to_vector(handle, "UInt8", col.u8()?),
+    #[cfg(feature = "dtype-u16")]
+    DataType::UInt16 => numeric_to_vector(handle, "UInt16", col.u16()?),
+    DataType::UInt32 => numeric_to_vector(handle, "UInt32", col.u32()?),
+    DataType::UInt64 => numeric_to_vector(handle, "UInt64", col.u64()?),
+    DataType::Float32 => numeric_to_vector(handle, "Float32", col.f32()?),
+    DataType::Float64 => numeric_to_vector(handle, "Float64", col.f64()?),
+    #[cfg(feature = "dtype-decimal")]
+    DataType::Decimal(_, _) => {
+      let ca = col.decimal()?;
+      let values = ca.physical().iter().map(|v| v.unwrap_or_default()).collect::<Vec<_>>();
+      jl_decimal_vector(handle, &values, validity(ca.physical()).as_deref(), ca.scale())
+    },
+    #[cfg(feature = "dtype-datetime")]
+    DataType::Datetime(tu, tz) => {
+      let ca = col.datetime()?;
+      let values = ca.physical().iter().map(|v| v.unwrap_or_default()).collect::<Vec<_>>();
+      let tz = tz.as_ref().map(|tz| tz.as_str());
+      jl_datetime_vector(handle, &values, validity(ca.physical()).as_deref(), time_unit_as_str(tu), tz)
+    },
+    DataType::String => jl_string_vector(handle, col.str()?.iter()),
+    DataType::Binary => jl_binary_vector(handle, col.binary()?.iter()),
+    #[cfg(feature = "dtype-categorical")]
+    DataType::Categorical(_, _) | DataType::Enum(_, _) => jl_categorical(handle, col, true),
+    DataType::List(_) => {
+      let ca = col.list()?.rechunk();
+      let values = ca.get_inner().into_column();
+      jl_list_vector(handle, &values, &list_offsets(col)?, validity(&ca).as_deref())
+    },
+    #[cfg(feature = "dtype-array")]
+    DataType::Array(_, _) => {
+      let ca = col.array()?.rechunk();
+      let values = ca.get_inner().into_column();
+      jl_list_vector(handle, &values, &list_offsets(col)?, validity(&ca).as_deref())
+    },
+    #[cfg(feature = "dtype-struct")]
+    DataType::Struct(_) => jl_struct_vector(handle, col, validity(col.struct_()?).as_deref()),
+    // everything else is extracted value by value on the julia side
+    _ => handle.local_scope::<_, 2>(|mut frame| {
+      let col = Value::new(&mut frame, polars_column_t { inner: col.clone() });
+      let _jl_column_vector = inline_static_ref!(JL_COLUMN_VECTOR_FUNCTION, Value, "Polars.FFI._jl_column_vector", frame);
+      match unsafe { _jl_column_vector.call(&mut frame, [col]) } {
+        Ok(v) => Ok(v.leak()),
+        Err(e) => Err(PolarsJlError::function_call("_jl_column_vector", e))?,
+      }
+    }),
   }
 }
+
+#[cfg(feature = "dtype-struct")]
+fn jl_struct_vector<'scope>(handle: &impl Target<'scope>, col: &Column, validity: Option<&[bool]>) -> PolarsJlResult<ValueRet> {
+  handle.local_scope::<_, 3>(|mut frame| {
+    let col = Value::new(&mut frame, polars_column_t { inner: col.clone() });
+    let valid = match validity {
+      Some(validity) => unsafe { jl_vector(&frame, "Bool", validity, None)?.root(&mut frame) },
+      None => Value::nothing(&frame),
+    };
+    let _jl_struct_vector = inline_static_ref!(JL_STRUCT_VECTOR_FUNCTION, Value, "Polars.FFI._jl_struct_vector", frame);
+    match unsafe { _jl_struct_vector.call(&mut frame, [col, valid]) } {
+      Ok(v) => Ok(v.leak()),
+      Err(e) => Err(PolarsJlError::function_call("_jl_struct_vector", e))?,
+    }
+  })
+}
+
+/// Passes the column to julia as 1-based refs into a pool of strings, 0 marks a null,
+/// and builds either the `(refs, pool)` tuple or the vector of strings from them.
+#[cfg(feature = "dtype-categorical")]
+fn jl_categorical<'scope>(handle: &impl Target<'scope>, col: &Column, vector: bool) -> PolarsJlResult<ValueRet> {
+  let (refs, pool) = match col.dtype().cat_physical()? {
+    CategoricalPhysical::U8 => categorical_parts(col.cat8()?),
+    CategoricalPhysical::U16 => categorical_parts(col.cat16()?),
+    CategoricalPhysical::U32 => categorical_parts(col.cat32()?),
+  };
+  handle.local_scope::<_, 3>(|mut frame| {
+    let refs = unsafe { jl_vector(&frame, "UInt32", &refs, None)?.root(&mut frame) };
+    let pool = unsafe { jl_string_vector(&frame, pool.into_iter().map(Some))?.root(&mut frame) };
+    let result = if vector {
+      let _jl_categorical_vector = inline_static_ref!(JL_CATEGORICAL_VECTOR_FUNCTION, Value, "Polars.FFI._jl_categorical_vector", frame);
+      unsafe { _jl_categorical_vector.call(&mut frame, [refs, pool]) }
+    } else {
+      let _jl_categorical_parts = inline_static_ref!(JL_CATEGORICAL_PARTS_FUNCTION, Value, "Polars.FFI._jl_categorical_parts", frame);
+      unsafe { _jl_categorical_parts.call(&mut frame, [refs, pool]) }
+    };
+    match result {
+      Ok(v) => Ok(v.leak()),
+      Err(e) => Err(PolarsJlError::function_call("_jl_categorical", e))?,
+    }
+  })
+}
+
+/// Enum pools hold every category in enum order, Categorical pools only the categories in use.
+#[cfg(feature = "dtype-categorical")]
+fn categorical_parts<T: PolarsCategoricalType>(ca: &CategoricalChunked<T>) -> (Vec<u32>, Vec<&str>) {
+  if let DataType::Enum(fcats, _) = ca.dtype() {
+    let refs = ca.physical().iter().map(|c| c.map_or(0, |c| c.as_cat() + 1)).collect();
+    return (refs, fcats.categories().values_iter().collect());
+  }
+  let mapping = ca.get_mapping();
+  let mut ids = PlHashMap::new();
+  let mut pool = Vec::new();
+  let refs = ca.physical().iter().map(|c| match c {
+    Some(c) => *ids.entry(c.as_cat()).or_insert_with(|| {
+      pool.push(mapping.cat_to_str(c.as_cat()).unwrap_or_default());
+      pool.len() as u32
+    }),
+    None => 0,
+  }).collect();
+  (refs, pool)
+}
+
+fn list_offsets(col: &Column) -> PolarsJlResult<Vec<i64>> {
+  match col.dtype() {
+    DataType::List(_) => {
+      let ca = col.list()?.rechunk();
+      Ok(ca.downcast_as_array().offsets().as_slice().to_vec())
+    },
+    #[cfg(feature = "dtype-array")]
+    DataType::Array(_, width) => Ok((0..=col.len()).map(|i| (i * width) as i64).collect()),
+    dt => Err(PolarsJlError::UnsupportedDataType(dt.to_string()))?,
+  }
+}
+
+fn jl_list_vector<'scope>(handle: &impl Target<'scope>, values: &Column, offsets: &[i64], validity: Option<&[bool]>) -> PolarsJlResult<ValueRet> {
+  handle.local_scope::<_, 4>(|mut frame| {
+    let values = unsafe { column_to_vector(&frame, values)?.root(&mut frame) };
+    let offsets = unsafe { jl_vector(&frame, "Int64", offsets, None)?.root(&mut frame) };
+    let valid = match validity {
+      Some(validity) => unsafe { jl_vector(&frame, "Bool", validity, None)?.root(&mut frame) },
+      None => Value::nothing(&frame),
+    };
+    let _jl_list_vector = inline_static_ref!(JL_LIST_VECTOR_FUNCTION, Value, "Polars.FFI._jl_list_vector", frame);
+    match unsafe { _jl_list_vector.call(&mut frame, [values, offsets, valid]) } {
+      Ok(v) => Ok(v.leak()),
+      Err(e) => Err(PolarsJlError::function_call("_jl_list_vector", e))?,
+    }
+  })
+}
+
+fn validity<T: PolarsDataType>(ca: &ChunkedArray<T>) -> Option<Vec<bool>> {
+  if ca.null_count() == 0 {
+    return None;
+  }
+  Some(ca.is_not_null().into_no_null_iter().collect())
+}
+
+fn numeric_to_vector<'scope, T: PolarsNumericType>(handle: &impl Target<'scope>, eltype: &'static str, ca: &ChunkedArray<T>) -> PolarsJlResult<ValueRet> {
+  let values = ca.iter().map(|v| v.unwrap_or_default()).collect::<Vec<_>>();
+  jl_vector(handle, eltype, &values, validity(ca).as_deref())
+}
+
+/// Checks that `data` holds whole values of `width` bytes and `validity` is empty or has one flag per value.
+fn check_bits(data: &[u8], width: usize, validity: &[bool]) -> PolarsResult<()> {
+  polars_ensure!(data.len() % width == 0, ShapeMismatch: "{} bytes are not a whole number of {}-byte values", data.len(), width);
+  polars_ensure!(
+    validity.is_empty() || validity.len() == data.len() / width,
+    ShapeMismatch: "{} validity flags for {} values", validity.len(), data.len() / width
+  );
+  Ok(())
+}
+
+/// Checks that `offsets` start at 0, never decrease and end at `len`, and that `validity`
+/// is empty or has one flag per value.
+fn check_offsets(offsets: &[i64], len: usize, validity: &[bool]) -> PolarsResult<()> {
+  polars_ensure!(offsets.first() == Some(&0), ShapeMismatch: "offsets must start at 0");
+  polars_ensure!(offsets.windows(2).all(|w| w[0] <= w[1]), ShapeMismatch: "offsets must not decrease");
+  polars_ensure!(
+    offsets.last() == Some(&(len as i64)),
+    OutOfBounds: "offsets end at {} but there are {} values", offsets[offsets.len() - 1], len
+  );
+  polars_ensure!(
+    validity.is_empty() || validity.len() == offsets.len() - 1,
+    ShapeMismatch: "{} validity flags for {} values", validity.len(), offsets.len() - 1
+  );
+  Ok(())
+}
+
+fn numeric_from_bits<T: PolarsNumericType>(name: PlSmallStr, data: &[u8], validity: &[bool]) -> PolarsResult<ChunkedArray<T>> {
+  check_bits(data, std::mem::size_of::<T::Native>(), validity)?;
+  let values = from_bits::<T::Native>(data);
+  Ok(if validity.is_empty() {
+    ChunkedArray::from_iter_values(name, values)
+  } else {
+    ChunkedArray::from_iter_options(name, values.zip(validity).map(|(v, ok)| ok.then_some(v)))
+  })
+}
@@ -1,10 +1,10 @@
-use std::fmt::Debug;
+use std::{cell::RefCell, fmt::Debug};
 
-use jlrs::{data::managed::{string::StringRet, value::ValueRet}, error::JlrsError, runtime::handle::ccall::throw_exception, weak_handle};
+use jlrs::{data::managed::{string::StringRet, symbol::SymbolRet, value::ValueRet}, error::JlrsError, weak_handle};
 pub use jlrs::prelude::*;
 use polars::error::PolarsError;
 
-use crate::utils::leak_string;
+use crate::utils::{leak_string, leak_symbol};
 
 pub type PolarsJlResult<T> = Result<T, PolarsJlError>;
 
@@ -26,10 +26,18 @@ pub enum PolarsJlError {
   NamedTupleMissingField(String),
   #[error("Unknown time unit: {0}")]
   TimeUnitError(String),
+  #[error("Unknown categorical physical type: {0}")]
+  CategoricalPhysicalError(String),
+  #[error("Unknown concat method: {0}")]
+  ConcatMethodError(String),
+  #[error("Unknown operation: {0}")]
+  OperationError(String),
   #[error("Unsupported data type: {0}")]
   UnsupportedDataType(String),
   #[error("Unsupported AnyValue variant: {0}")]
   UnsupportedAnyValue(&'static str),
+  #[error("Rust panic in {0}: {1}")]
+  Panic(&'static str, String),
 }
 
 impl From<Box<JlrsError>> for PolarsJlError {
@@ -56,34 +64,112 @@ impl PolarsJlError {
   pub fn function_call<E: Debug>(func: &'static str, e: E) -> Self {
     PolarsJlError::CallFunctionError(func, Some(format!("{e:?}")))
   }
+
+  /// The kind of a Polars or IO error or of an invalid argument, which selects the exception
+  /// type thrown in Julia. Errors of the bindings themselves have none.
+  pub fn kind(&self) -> Option<&'static str> {
+    match self {
+      PolarsJlError::PolarsError(e) => Some(polars_error_kind(e)),
+      PolarsJlError::IoError(_) => Some("IO"),
+      PolarsJlError::ConcatMethodError(_) | PolarsJlError::TimeUnitError(_) | PolarsJlError::NamedTupleMissingField(_)
+        | PolarsJlError::CategoricalPhysicalError(_) | PolarsJlError::OperationError(_) => Some("InvalidArgument"),
+      PolarsJlError::UnsupportedDataType(_) | PolarsJlError::UnsupportedAnyValue(_) => Some("InvalidOperation"),
+      _ => None,
+    }
+  }
+}
+
+pub fn polars_error_kind(err: &PolarsError) -> &'static str {
+  match err {
+    PolarsError::AssertionError(_) => "AssertionError",
+    PolarsError::ColumnNotFound(_) => "ColumnNotFound",
+    PolarsError::ComputeError(_) => "ComputeError",
+    PolarsError::Duplicate(_) => "Duplicate",
+    PolarsError::InvalidOperation(_) => "InvalidOperation",
+    PolarsError::IO { .. } => "IO",
+    PolarsError::NoData(_) => "NoData",
+    PolarsError::OutOfBounds(_) => "OutOfBounds",
+    PolarsError::SchemaFieldNotFound(_) => "SchemaFieldNotFound",
+    PolarsError::SchemaMismatch(_) => "SchemaMismatch",
+    PolarsError::ShapeMismatch(_) => "ShapeMismatch",
+    PolarsError::SQLInterface(_) => "SQLInterface",
+    PolarsError::SQLSyntax(_) => "SQLSyntax",
+    PolarsError::StringCacheMismatch(_) => "StringCacheMismatch",
+    PolarsError::StructFieldNotFound(_) => "StructFieldNotFound",
+    PolarsError::Context { error, .. } => polars_error_kind(error),
+  }
+}
+
+thread_local! {
+  // the typed error of the last failed call on this thread, see `catch_panic`
+  static LAST_ERROR: RefCell<Option<polars_error_t>> = const { RefCell::new(None) };
+}
+
+/// Runs the body of an exported function, turning a Rust panic into a Julia exception
+/// instead of letting it unwind across the FFI boundary.
+/// Every error is returned as a `JlrsError`, so jlrs throws it once the Rust frames are gone.
+/// Errors with a kind are also stashed as a `polars_error_t`, which the wrappers in
+/// `Polars.FFI` take to throw the matching `Polars.Errors` exception instead. Panics and
+/// errors of the bindings themselves stay a `JlrsCore.JlrsError`.
+pub(crate) fn catch_panic<T, F: FnOnce() -> PolarsJlResult<T>>(func: &'static str, f: F) -> JlrsResult<T> {
+  // an error nobody took belongs to an earlier call
+  LAST_ERROR.with(|last| last.borrow_mut().take());
+  match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+    Ok(Ok(result)) => Ok(result),
+    Ok(Err(err)) if err.kind().is_some() => {
+      let msg = err.to_string();
+      LAST_ERROR.with(|last| *last.borrow_mut() = Some(polars_error_t { inner: err, func }));
+      Err(Box::new(JlrsError::exception(msg)))
+    },
+    Ok(Err(err)) => Err(err)?,
+    Err(payload) => {
+      let msg = if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+      } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+      } else {
+        "unknown panic payload".to_string()
+      };
+      Err(PolarsJlError::Panic(func, msg))?
+    },
+  }
 }
 
 #[derive(Debug, OpaqueType)]
 #[allow(non_camel_case_types)]
 pub struct polars_error_t {
   pub(crate) inner: PolarsJlError,
+  pub(crate) func: &'static str,
 }
 
 impl polars_error_t {
-  pub fn message(&self) -> StringRet {
-    let msg = format!("{}", self.inner);
-    leak_string(msg)
+  pub fn message(&self) -> JlrsResult<StringRet> {
+    catch_panic("polars_error_message", || {
+      let msg = format!("{}", self.inner);
+      Ok(leak_string(msg))
+    })
   }
 
-  pub fn throw(self) -> ! {
-    let exception = self.into();
-    unsafe { throw_exception(exception) }
+  pub fn kind(&self) -> JlrsResult<SymbolRet> {
+    catch_panic("polars_error_kind", || Ok(leak_symbol(self.inner.kind().unwrap_or("Other"))))
   }
-}
 
-impl From<polars_error_t> for ValueRet {
-  fn from(err: polars_error_t) -> Self {
-    match weak_handle!() {
-      Ok(handle) => {
-        let exception = Value::new(&handle, err);
-        exception.leak()
-      },
-      Err(_) => PolarsJlError::WeakHandleError("ValueRet::from::<polars_error_t>").panic(),
-    }
+  pub fn function(&self) -> JlrsResult<StringRet> {
+    catch_panic("polars_error_function", || Ok(leak_string(self.func)))
+  }
+
+  /// Takes the error stashed by the last failed call on this thread, `nothing` if there is none.
+  pub fn take_last() -> JlrsResult<ValueRet> {
+    // taken before catch_panic, which clears it
+    let err = LAST_ERROR.with(|last| last.borrow_mut().take());
+    catch_panic("polars_error_take_last", move || {
+      match weak_handle!() {
+        Ok(handle) => match err {
+          Some(err) => Ok(Value::new(&handle, err).leak()),
+          None => Ok(Value::nothing(&handle).leak()),
+        },
+        Err(_) => Err(PolarsJlError::WeakHandleError("polars_error_t::take_last")),
+      }
+    })
   }
 }
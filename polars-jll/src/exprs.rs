@@ -0,0 +1,186 @@
+use polars::prelude::*;
+use jlrs::{data::managed::{ccall_ref::CCallRef, string::StringRet, value::typed::TypedValue}, prelude::*};
+
+use crate::{errors::{catch_panic, PolarsJlError}, utils::{leak_string, leak_value, CCallRefExt, TypedVecExt}, AnyValueRef};
+
+/// A lazy expression, evaluated against a frame by `select` and `with_columns`.
+#[derive(Debug, OpaqueType)]
+#[allow(non_camel_case_types)]
+pub struct polars_expr_t {
+  pub(crate) inner: Expr,
+}
+
+pub type ExprRet = jlrs::data::managed::ccall_ref::CCallRefRet<polars_expr_t>;
+pub type ExprRef<'scope> = jlrs::data::managed::ccall_ref::CCallRef<'scope, ExprValue<'scope, 'static>>;
+pub type ExprValue<'scope, 'data> = TypedValue<'scope, 'data, polars_expr_t>;
+
+impl polars_expr_t {
+  pub fn col(name: JuliaString) -> JlrsResult<ExprRet> {
+    catch_panic("polars_expr_col", || {
+      Ok(leak_value(Self { inner: col(name.as_str()?) }))
+    })
+  }
+
+  pub fn lit(value: AnyValueRef) -> JlrsResult<ExprRet> {
+    catch_panic("polars_expr_lit", || {
+      let value = value.tracked_map(|v| v.inner.clone())?;
+      Ok(leak_value(Self { inner: lit(Scalar::new(value.dtype(), value)) }))
+    })
+  }
+
+  /// Like `lit`, but a number takes the dtype of the other operand when it fits, so
+  /// `col("x") + 1` keeps the dtype of x.
+  pub fn lit_dyn(value: AnyValueRef) -> JlrsResult<ExprRet> {
+    catch_panic("polars_expr_lit_dyn", || {
+      let value = value.tracked_map(|v| v.inner.clone())?;
+      let inner = match value.dtype() {
+        dt if dt.is_integer() => lit(value.try_extract::<i128>()?),
+        dt if dt.is_float() => lit(value.try_extract::<f64>()?),
+        dt => lit(Scalar::new(dt, value)),
+      };
+      Ok(leak_value(Self { inner }))
+    })
+  }
+
+  pub fn display(&self) -> JlrsResult<StringRet> {
+    catch_panic("polars_expr_display", || {
+      Ok(leak_string(format!("{}", self.inner)))
+    })
+  }
+
+  pub fn neg(&self) -> JlrsResult<ExprRet> {
+    catch_panic("polars_expr_neg", || {
+      Ok(leak_value(Self { inner: -self.inner.clone() }))
+    })
+  }
+
+  pub fn alias(&self, name: JuliaString) -> JlrsResult<ExprRet> {
+    catch_panic("polars_expr_alias", || {
+      Ok(leak_value(Self { inner: self.inner.clone().alias(name.as_str()?) }))
+    })
+  }
+
+  /// Elementwise `op` of two expressions, the same ops as `polars_column_binary`.
+  /// op: :add, :sub, :mul, :div, :rem, :and, :or, :eq, :ne, :lt, :le, :gt, :ge
+  pub fn binary(&self, other: ExprRef, op: CCallRef<Symbol>) -> JlrsResult<ExprRet> {
+    catch_panic("polars_expr_binary", || {
+      let (a, b) = (self.inner.clone(), other.tracked_map(|e| e.inner.clone())?);
+      let e = match op.as_managed()?.as_str()? {
+        "add" => a + b,
+        "sub" => a - b,
+        "mul" => a * b,
+        "div" => binary_expr(a, Operator::TrueDivide, b),
+        "rem" => a % b,
+        "and" => a.and(b),
+        "or" => a.or(b),
+        "eq" => a.eq(b),
+        "ne" => a.neq(b),
+        "lt" => a.lt(b),
+        "le" => a.lt_eq(b),
+        "gt" => a.gt(b),
+        "ge" => a.gt_eq(b),
+        op => return Err(PolarsJlError::OperationError(op.to_string()))?,
+      };
+      Ok(leak_value(Self { inner: e }))
+    })
+  }
+
+  /// Aggregates to one value, or one value per group.
+  /// op: :sum, :mean, :min, :max, :median, :product, :first, :last, :count, :n_unique, :len
+  pub fn agg(&self, op: CCallRef<Symbol>) -> JlrsResult<ExprRet> {
+    catch_panic("polars_expr_agg", || {
+      let e = self.inner.clone();
+      let e = match op.as_managed()?.as_str()? {
+        "sum" => e.sum(),
+        "mean" => e.mean(),
+        "min" => e.min(),
+        "max" => e.max(),
+        "median" => e.median(),
+        "product" => e.product(),
+        "first" => e.first(),
+        "last" => e.last(),
+        "count" => e.count(),
+        "n_unique" => e.n_unique(),
+        "len" => e.len(),
+        op => return Err(PolarsJlError::OperationError(op.to_string()))?,
+      };
+      Ok(leak_value(Self { inner: e }))
+    })
+  }
+
+  /// Running aggregate, from the end if `reverse`. cum_count counts the non-null values.
+  /// op: :sum, :prod, :min, :max, :count
+  pub fn cum(&self, op: CCallRef<Symbol>, reverse: bool) -> JlrsResult<ExprRet> {
+    catch_panic("polars_expr_cum", || {
+      let e = self.inner.clone();
+      let e = match op.as_managed()?.as_str()? {
+        "sum" => e.cum_sum(reverse),
+        "prod" => e.cum_prod(reverse),
+        "min" => e.cum_min(reverse),
+        "max" => e.cum_max(reverse),
+        "count" => e.cum_count(reverse),
+        op => return Err(PolarsJlError::OperationError(op.to_string()))?,
+      };
+      Ok(leak_value(Self { inner: e }))
+    })
+  }
+
+  /// method: :average, :min, :max, :dense, :ordinal
+  pub fn rank(&self, method: CCallRef<Symbol>, descending: bool) -> JlrsResult<ExprRet> {
+    catch_panic("polars_expr_rank", || {
+      let method = match method.as_managed()?.as_str()? {
+        "average" => RankMethod::Average,
+        "min" => RankMethod::Min,
+        "max" => RankMethod::Max,
+        "dense" => RankMethod::Dense,
+        "ordinal" => RankMethod::Ordinal,
+        m => return Err(PolarsJlError::OperationError(m.to_string()))?,
+      };
+      let e = self.inner.clone().rank(RankOptions { method, descending }, None);
+      Ok(leak_value(Self { inner: e }))
+    })
+  }
+
+  /// Moves values `n` rows down (up if negative), the vacated rows are null.
+  pub fn shift(&self, n: i64) -> JlrsResult<ExprRet> {
+    catch_panic("polars_expr_shift", || {
+      Ok(leak_value(Self { inner: self.inner.clone().shift(lit(n)) }))
+    })
+  }
+
+  /// Difference with the value `n` rows before.
+  pub fn diff(&self, n: i64) -> JlrsResult<ExprRet> {
+    catch_panic("polars_expr_diff", || {
+      Ok(leak_value(Self { inner: self.inner.clone().diff(lit(n), NullBehavior::Ignore) }))
+    })
+  }
+
+  /// Relative change from the value `n` rows before.
+  pub fn pct_change(&self, n: i64) -> JlrsResult<ExprRet> {
+    catch_panic("polars_expr_pct_change", || {
+      Ok(leak_value(Self { inner: self.inner.clone().pct_change(lit(n)) }))
+    })
+  }
+
+  /// Evaluates the expression per group of `partition_by`, in the order of `order_by`
+  /// if it is not empty. The result is mapped back to the rows of each group.
+  pub fn over(&self, partition_by: TypedVector<ExprValue>, order_by: TypedVector<ExprValue>, descending: bool) -> JlrsResult<ExprRet> {
+    catch_panic("polars_expr_over", || {
+      let partition_by = partition_by.extract_box(|e| e.inner.clone())?;
+      let order_by = order_by.extract_box(|e| e.inner.clone())?;
+      let options = SortOptions { descending, ..Default::default() };
+      let e = self.inner.clone().over_with_options(
+        (!partition_by.is_empty()).then_some(partition_by),
+        (!order_by.is_empty()).then_some((order_by, options)),
+        WindowMapping::default(),
+      )?;
+      Ok(leak_value(Self { inner: e }))
+    })
+  }
+}
+
+/// The elements of each list in `list.eval` or of each group in a pivot aggregation,
+/// `pl.element()` in python polars, which the rust crate only spells as the unnamed column.
+pub(crate) fn element() -> Expr {
+  col("")
+}
@@ -1,7 +1,7 @@
-use polars::prelude::*;
-use jlrs::{data::{managed::{ccall_ref::CCallRef, value::typed::TypedValue}, types::abstract_type::IO}, prelude::*, weak_handle};
+use polars::{io::{partition::write_partitioned_dataset, HiveOptions}, prelude::*};
+use jlrs::{data::{managed::{ccall_ref::CCallRef, value::{typed::TypedValue, ValueRet}}, types::abstract_type::IO}, prelude::*, weak_handle};
 
-use crate::{errors::PolarsJlError, polars_column_t, utils::{leak_value, IOWrapper, TypedVecExt}, ColumnRet, ColumnValue};
+use crate::{columns::column_to_vector, errors::{catch_panic, PolarsJlError, PolarsJlResult}, exprs::element, polars_column_t, polars_lazyframe_t, polars_value_t, utils::{extract_strings, leak_value, CCallRefExt, IOWrapper, JuliaValueExt, TypedVecExt}, value_types::duration_from_str, AnyValueRet, ColumnRef, ColumnRet, ColumnValue, ExprValue, LazyFrameRet, ValueTypeValue};
 
 #[derive(Debug, OpaqueType)]
 #[allow(non_camel_case_types)]
@@ -14,48 +14,456 @@ pub type DataFrameRef<'scope> = jlrs::data::managed::ccall_ref::CCallRef<'scope,
 pub type DataFrameValue<'scope, 'data> = TypedValue<'scope, 'data, polars_dataframe_t>;
 
 impl polars_dataframe_t {
-  pub fn new_empty() -> DataFrameRet {
-    leak_value(Self { inner: DataFrame::empty() })
+  pub fn new_empty() -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_new_empty", || {
+      Ok(leak_value(Self { inner: DataFrame::empty() }))
+    })
   }
 
   pub fn from_cols(cols: TypedVector<ColumnValue>) -> JlrsResult<DataFrameRet> {
-    let cols = cols.extract_box(|c| c.inner.clone())?;
-    Ok(leak_value(Self { inner: DataFrame::new(cols).unwrap() }))
+    catch_panic("polars_dataframe_from_cols", || {
+      let cols = cols.extract_box(|c| c.inner.clone())?;
+      let df = DataFrame::new(cols).map_err(PolarsJlError::from)?;
+      Ok(leak_value(Self { inner: df }))
+    })
   }
 
-  pub fn height(&self) -> usize {
-    self.inner.height()
+  /// how: :vertical, :vertical_relaxed, :horizontal, :diagonal, :diagonal_relaxed
+  pub fn concat(dfs: TypedVector<DataFrameValue>, how: CCallRef<Symbol>, rechunk: bool) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_concat", || {
+      let how = how.as_managed()?.as_str()?;
+      let lfs = dfs.extract_box(|df| df.inner.clone().lazy())?;
+      let lf = concat_how(lfs, how, UnionArgs { rechunk, ..Default::default() })?;
+      let df = lf.collect().map_err(PolarsJlError::from)?;
+      Ok(leak_value(Self { inner: df }))
+    })
+  }
+
+  /// Reads every file matched by the globs, directories or file names in `paths` in parallel
+  /// and concatenates them in order, `how` reconciles differing schemas as in `concat`.
+  /// A parquet or ipc directory or glob is scanned as one dataset, so its files share a schema
+  /// and hive partitions in the directory names (e.g. `year=2024/`) become columns.
+  /// `include_file_paths` names a column holding the path of each row's file, or is `nothing`.
+  /// format: :parquet, :csv, :ipc
+  pub fn read_files(paths: TypedVector<JuliaString>, format: CCallRef<Symbol>, how: CCallRef<Symbol>, include_file_paths: CCallRef<Value>) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_read_files", || {
+      let paths = extract_strings(paths)?;
+      let include_file_paths = match include_file_paths.as_cast_opt::<JuliaString>()? {
+        Some(name) => Some(PlSmallStr::from_str(name.as_str()?)),
+        None => None,
+      };
+      let format = format.as_managed()?;
+      let format = format.as_str()?;
+      let mut lfs = Vec::new();
+      for path in &paths {
+        let dataset = format != "csv" && (path.contains(['*', '?', '[']) || std::path::Path::new(path).is_dir());
+        if dataset {
+          lfs.push(scan_files(format, PlPath::new(path), include_file_paths.clone(), true)?);
+          continue;
+        }
+        // one scan per file, so that each keeps its own schema until the concat
+        for file in expand_paths(&[PlPath::new(path)], true, None)?.iter() {
+          lfs.push(scan_files(format, file.clone(), include_file_paths.clone(), false)?);
+        }
+      }
+      if lfs.is_empty() {
+        return Err(PolarsJlError::from(polars_err!(NoData: "no files found at {:?}", paths)));
+      }
+      let df = concat_how(lfs, how.as_managed()?.as_str()?, UnionArgs::default())?.collect()?;
+      Ok(leak_value(Self { inner: df }))
+    })
+  }
+
+  pub fn height(&self) -> JlrsResult<usize> {
+    catch_panic("polars_dataframe_height", || Ok(self.inner.height()))
   }
 
   pub fn read_parquet(path: JuliaString) -> JlrsResult<DataFrameRet> {
-    let path = path.as_str()?;
-    let file = std::fs::File::open(path).map_err(PolarsJlError::from)?;
-    let df = ParquetReader::new(file).finish().map_err(PolarsJlError::from)?;
-    Ok(leak_value(Self { inner: df }))
+    catch_panic("polars_dataframe_read_parquet", || {
+      let path = path.as_str()?;
+      let file = std::fs::File::open(path).map_err(PolarsJlError::from)?;
+      let df = ParquetReader::new(file).finish().map_err(PolarsJlError::from)?;
+      Ok(leak_value(Self { inner: df }))
+    })
   }
 
   pub fn write_parquet(&mut self, path: JuliaString) -> JlrsResult<()> {
-    let path = path.as_str()?;
-    let file = std::fs::File::create(path).map_err(PolarsJlError::from)?;
-    ParquetWriter::new(file).finish(&mut self.inner).map_err(PolarsJlError::from)?;
-    Ok(())
+    catch_panic("polars_dataframe_write_parquet", || {
+      let path = path.as_str()?;
+      let file = std::fs::File::create(path).map_err(PolarsJlError::from)?;
+      ParquetWriter::new(file).finish(&mut self.inner).map_err(PolarsJlError::from)?;
+      Ok(())
+    })
+  }
+
+  /// Writes one directory per unique combination of the `partition_by` columns, laid out
+  /// as `path/key=value/00000000.parquet` so that `scan_parquet` reads them back as columns.
+  /// Partitions estimated at more than `chunk_size` bytes in memory are split over several files.
+  pub fn write_parquet_partitioned(&mut self, path: JuliaString, partition_by: TypedVector<JuliaString>, chunk_size: u64) -> JlrsResult<()> {
+    catch_panic("polars_dataframe_write_parquet_partitioned", || {
+      let path = PlPath::new(path.as_str()?);
+      let partition_by = extract_strings(partition_by)?.into_iter().map(PlSmallStr::from).collect();
+      if chunk_size == 0 {
+        Err(polars_err!(InvalidOperation: "chunk_size must be positive"))?
+      }
+      // no partition of a frame in memory is larger than usize::MAX bytes on a 32-bit target
+      let chunk_size = usize::try_from(chunk_size).unwrap_or(usize::MAX);
+      write_partitioned_dataset(&mut self.inner, path.as_ref(), partition_by, &ParquetWriteOptions::default(), None, chunk_size)?;
+      Ok(())
+    })
   }
 
   pub fn show(&self, io: CCallRef<IO>) -> JlrsResult<()> {
-    match weak_handle!() {
-      Ok(handle) => {
-        use std::io::Write;
-        let mut w = IOWrapper::new(&handle, &io);
-        writeln!(w, "{}", self.inner).map_err(PolarsJlError::from)?;
-        Ok(())
-      },
-      Err(_) => PolarsJlError::WeakHandleError("polars_dataframe_t::show").panic(),
-    }
+    catch_panic("polars_dataframe_show", || {
+      match weak_handle!() {
+        Ok(handle) => {
+          use std::io::Write;
+          let mut w = IOWrapper::new(&handle, &io);
+          writeln!(w, "{}", self.inner).map_err(PolarsJlError::from)?;
+          Ok(())
+        },
+        Err(_) => Err(PolarsJlError::WeakHandleError("polars_dataframe_t::show"))?,
+      }
+    })
   }
 
   pub fn get_column(&self, name: JuliaString) -> JlrsResult<ColumnRet> {
-    let name = name.as_str()?;
-    let col = self.inner.column(name).map_err(PolarsJlError::from)?;
-    Ok(leak_value(polars_column_t { inner: col.clone() }))
+    catch_panic("polars_dataframe_get_column", || {
+      let name = name.as_str()?;
+      let col = self.inner.column(name).map_err(PolarsJlError::from)?;
+      Ok(leak_value(polars_column_t { inner: col.clone() }))
+    })
+  }
+
+  /// Casts the columns `names[i]` to `dtypes[i]`, other columns are kept as they are.
+  pub fn cast(&self, names: TypedVector<JuliaString>, dtypes: TypedVector<ValueTypeValue>, strict: bool) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_cast", || {
+      let names = extract_strings(names)?;
+      let dtypes = dtypes.extract_box(|dt| dt.inner.clone())?;
+      let mut df = self.inner.clone();
+      for (name, dtype) in names.iter().zip(dtypes.iter()) {
+        let idx = df.try_get_column_index(name)?;
+        let col = &df.get_columns()[idx];
+        let col = match strict {
+          true => col.strict_cast(dtype)?,
+          false => col.cast(dtype)?,
+        };
+        df.replace_column(idx, col)?;
+      }
+      Ok(leak_value(Self { inner: df }))
+    })
+  }
+
+  /// One row per element of the List or Array columns `names`, the other values are repeated.
+  /// The lists of a row must have equal lengths.
+  pub fn explode(&self, names: TypedVector<JuliaString>) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_explode", || {
+      let df = self.inner.explode(extract_strings(names)?)?;
+      Ok(leak_value(Self { inner: df }))
+    })
+  }
+
+  /// A single row holding every column as a List.
+  pub fn implode(&self) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_implode", || {
+      let cols = self.inner.get_columns().iter()
+        .map(|c| Ok(c.implode()?.into_series().into_column()))
+        .collect::<PolarsJlResult<Vec<_>>>()?;
+      Ok(leak_value(Self { inner: DataFrame::new(cols)? }))
+    })
+  }
+
+  /// The rows that are unique over the `subset` columns, all columns if empty.
+  /// keep: :first, :last, :none, :any
+  pub fn unique(&self, subset: TypedVector<JuliaString>, keep: CCallRef<Symbol>, maintain_order: bool) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_unique", || {
+      let keep = match keep.as_managed()?.as_str()? {
+        "first" => UniqueKeepStrategy::First,
+        "last" => UniqueKeepStrategy::Last,
+        "none" => UniqueKeepStrategy::None,
+        "any" => UniqueKeepStrategy::Any,
+        k => return Err(PolarsJlError::OperationError(k.to_string()))?,
+      };
+      let subset = extract_strings(subset)?;
+      let subset = (!subset.is_empty()).then_some(subset.as_slice());
+      let df = match maintain_order {
+        true => self.inner.unique_stable(subset, keep, None)?,
+        false => self.inner.unique::<String, String>(subset, keep, None)?,
+      };
+      Ok(leak_value(Self { inner: df }))
+    })
+  }
+
+  /// Number of distinct rows over the `subset` columns, all columns if empty.
+  pub fn n_unique(&self, subset: TypedVector<JuliaString>) -> JlrsResult<usize> {
+    catch_panic("polars_dataframe_n_unique", || {
+      let df = select_subset(&self.inner, subset)?;
+      match df.get_columns() {
+        [] => Ok(0),
+        [c] => Ok(c.n_unique()?),
+        _ => Ok(df.group_by(df.get_column_names_owned())?.get_groups().len()),
+      }
+    })
+  }
+
+  /// Bool mask of the rows whose `subset` values occur more than once, all columns if empty.
+  pub fn is_duplicated(&self, subset: TypedVector<JuliaString>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_dataframe_is_duplicated", || {
+      let mask = select_subset(&self.inner, subset)?.is_duplicated()?;
+      Ok(leak_value(polars_column_t { inner: mask.into_column() }))
+    })
+  }
+
+  /// Bool mask of the rows whose `subset` values occur exactly once, all columns if empty.
+  pub fn is_unique(&self, subset: TypedVector<JuliaString>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_dataframe_is_unique", || {
+      let mask = select_subset(&self.inner, subset)?.is_unique()?;
+      Ok(leak_value(polars_column_t { inner: mask.into_column() }))
+    })
+  }
+
+  /// The rows without nulls in the `subset` columns, all columns if empty.
+  pub fn drop_nulls(&self, subset: TypedVector<JuliaString>) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_drop_nulls", || {
+      let subset = extract_strings(subset)?;
+      let subset = (!subset.is_empty()).then_some(subset.as_slice());
+      Ok(leak_value(Self { inner: self.inner.drop_nulls(subset)? }))
+    })
+  }
+
+  /// Wide frame with one column per unique value of the `on` columns, holding `values`
+  /// grouped by `index`. Empty `index` or `values` take all remaining columns.
+  /// aggregate_function: nothing, :first, :last, :sum, :mean, :median, :min, :max, :len
+  pub fn pivot(&self, on: TypedVector<JuliaString>, index: TypedVector<JuliaString>, values: TypedVector<JuliaString>, aggregate_function: CCallRef<Value>, sort_columns: bool, separator: JuliaString) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_pivot", || {
+      let agg = match aggregate_function.as_cast_opt::<Symbol>()? {
+        Some(agg) => {
+          let element = element();
+          Some(match agg.as_str()? {
+            "first" => element.first(),
+            "last" => element.last(),
+            "sum" => element.sum(),
+            "mean" => element.mean(),
+            "median" => element.median(),
+            "min" => element.min(),
+            "max" => element.max(),
+            "len" => element.len(),
+            a => return Err(PolarsJlError::OperationError(a.to_string()))?,
+          })
+        }
+        None => None,
+      };
+      let index = extract_strings(index)?;
+      let values = extract_strings(values)?;
+      let df = pivot::pivot_stable(
+        &self.inner,
+        extract_strings(on)?,
+        (!index.is_empty()).then_some(index),
+        (!values.is_empty()).then_some(values),
+        sort_columns,
+        agg,
+        Some(separator.as_str()?),
+      )?;
+      Ok(leak_value(Self { inner: df }))
+    })
+  }
+
+  /// Long frame with the `on` columns stacked into `variable_name` and `value_name`,
+  /// the `index` columns repeated. Empty `on` takes all columns not in `index`.
+  pub fn unpivot(&self, on: TypedVector<JuliaString>, index: TypedVector<JuliaString>, variable_name: JuliaString, value_name: JuliaString) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_unpivot", || {
+      let args = UnpivotArgsIR {
+        on: extract_strings(on)?.into_iter().map(PlSmallStr::from).collect(),
+        index: extract_strings(index)?.into_iter().map(PlSmallStr::from).collect(),
+        variable_name: Some(variable_name.as_str()?.into()),
+        value_name: Some(value_name.as_str()?.into()),
+      };
+      let df = self.inner.unpivot2(args)?;
+      Ok(leak_value(Self { inner: df }))
+    })
+  }
+
+  /// A lazy query over a copy of the frame.
+  pub fn lazy(&self) -> JlrsResult<LazyFrameRet> {
+    catch_panic("polars_dataframe_lazy", || {
+      Ok(leak_value(polars_lazyframe_t { inner: self.inner.clone().lazy() }))
+    })
   }
+
+  /// A frame of the columns computed by `exprs`.
+  pub fn select(&self, exprs: TypedVector<ExprValue>) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_select", || {
+      let exprs = exprs.extract_box(|e| e.inner.clone())?;
+      let df = self.inner.clone().lazy().select(exprs).collect()?;
+      Ok(leak_value(Self { inner: df }))
+    })
+  }
+
+  /// Adds the columns computed by `exprs`, replacing columns with the same name.
+  pub fn with_columns(&self, exprs: TypedVector<ExprValue>) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_with_columns", || {
+      let exprs = exprs.extract_box(|e| e.inner.clone())?;
+      let df = self.inner.clone().lazy().with_columns(exprs).collect()?;
+      Ok(leak_value(Self { inner: df }))
+    })
+  }
+
+  /// Aggregates `aggs` over a window `period` long ending at each row of the sorted
+  /// `index_column`, shifted by `offset`. closed: :left, :right, :both, :none
+  pub fn rolling(&self, index_column: JuliaString, period: JuliaString, offset: JuliaString, closed: CCallRef<Symbol>, group_by: TypedVector<JuliaString>, aggs: TypedVector<ExprValue>) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_rolling", || {
+      let options = RollingGroupOptions {
+        period: duration_from_str(period.as_str()?)?,
+        offset: duration_from_str(offset.as_str()?)?,
+        closed_window: closed_window(closed.as_managed()?.as_str()?)?,
+        ..Default::default()
+      };
+      let group_by = extract_strings(group_by)?.into_iter().map(col).collect::<Vec<_>>();
+      let aggs = aggs.extract_box(|e| e.inner.clone())?;
+      let df = self.inner.clone().lazy()
+        .rolling(col(index_column.as_str()?), group_by, options)
+        .agg(aggs)
+        .collect()?;
+      Ok(leak_value(Self { inner: df }))
+    })
+  }
+
+  /// Aggregates `aggs` over windows starting every `every` along the sorted `index_column`,
+  /// each `period` long and shifted by `offset`.
+  /// closed: :left, :right, :both, :none
+  /// label: :left, :right, :datapoint
+  /// start_by: :window, :datapoint, :monday, ..., :sunday
+  pub fn group_by_dynamic(&self, index_column: JuliaString, every: JuliaString, period: JuliaString, offset: JuliaString, closed: CCallRef<Symbol>, label: CCallRef<Symbol>, start_by: CCallRef<Symbol>, include_boundaries: bool, group_by: TypedVector<JuliaString>, aggs: TypedVector<ExprValue>) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_dataframe_group_by_dynamic", || {
+      let label = match label.as_managed()?.as_str()? {
+        "left" => Label::Left,
+        "right" => Label::Right,
+        "datapoint" => Label::DataPoint,
+        l => return Err(PolarsJlError::OperationError(l.to_string()))?,
+      };
+      let start_by = match start_by.as_managed()?.as_str()? {
+        "window" => StartBy::WindowBound,
+        "datapoint" => StartBy::DataPoint,
+        "monday" => StartBy::Monday,
+        "tuesday" => StartBy::Tuesday,
+        "wednesday" => StartBy::Wednesday,
+        "thursday" => StartBy::Thursday,
+        "friday" => StartBy::Friday,
+        "saturday" => StartBy::Saturday,
+        "sunday" => StartBy::Sunday,
+        s => return Err(PolarsJlError::OperationError(s.to_string()))?,
+      };
+      let options = DynamicGroupOptions {
+        every: duration_from_str(every.as_str()?)?,
+        period: duration_from_str(period.as_str()?)?,
+        offset: duration_from_str(offset.as_str()?)?,
+        closed_window: closed_window(closed.as_managed()?.as_str()?)?,
+        label,
+        start_by,
+        include_boundaries,
+        ..Default::default()
+      };
+      let group_by = extract_strings(group_by)?.into_iter().map(col).collect::<Vec<_>>();
+      let aggs = aggs.extract_box(|e| e.inner.clone())?;
+      let df = self.inner.clone().lazy()
+        .group_by_dynamic(col(index_column.as_str()?), group_by, options)
+        .agg(aggs)
+        .collect()?;
+      Ok(leak_value(Self { inner: df }))
+    })
+  }
+
+  /// All values of row `idx` as a Struct value.
+  pub fn get_row(&self, idx: usize) -> JlrsResult<AnyValueRet> {
+    catch_panic("polars_dataframe_get_row", || {
+      Ok(leak_value(row_value(&self.inner, idx)?))
+    })
+  }
+
+  /// The single row where the boolean `mask` is true.
+  pub fn row_by_predicate(&self, mask: ColumnRef) -> JlrsResult<AnyValueRet> {
+    catch_panic("polars_dataframe_row_by_predicate", || {
+      let mask = mask.tracked_map(|c| c.inner.clone())?;
+      let df = self.inner.filter(mask.bool()?).map_err(PolarsJlError::from)?;
+      match df.height() {
+        1 => Ok(leak_value(row_value(&df, 0)?)),
+        0 => Err(polars_err!(NoData: "predicate matched no rows"))?,
+        n => Err(polars_err!(ComputeError: "predicate matched {} rows, expected 1", n))?,
+      }
+    })
+  }
+
+  /// Rows `offset..offset + n` as a Julia vector of NamedTuples, converted column by column.
+  pub fn rows(&self, offset: usize, n: usize) -> JlrsResult<ValueRet> {
+    catch_panic("polars_dataframe_rows", || {
+      match weak_handle!() {
+        #[cfg(feature = "dtype-struct")]
+        Ok(handle) => {
+          let rows = self.inner.slice(offset as i64, n).into_struct(PlSmallStr::EMPTY);
+          column_to_vector(&handle, &rows.into_series().into_column())
+        },
+        #[cfg(not(feature = "dtype-struct"))]
+        Ok(_) => Err(PolarsJlError::UnsupportedDataType("Struct".to_string()))?,
+        Err(_) => Err(PolarsJlError::WeakHandleError("polars_dataframe_t::rows"))?,
+      }
+    })
+  }
+}
+
+fn closed_window(closed: &str) -> PolarsJlResult<ClosedWindow> {
+  match closed {
+    "left" => Ok(ClosedWindow::Left),
+    "right" => Ok(ClosedWindow::Right),
+    "both" => Ok(ClosedWindow::Both),
+    "none" => Ok(ClosedWindow::None),
+    c => Err(PolarsJlError::OperationError(c.to_string())),
+  }
+}
+
+#[cfg(feature = "dtype-struct")]
+fn row_value(df: &DataFrame, idx: usize) -> PolarsJlResult<polars_value_t> {
+  let values = df.get_columns().iter()
+    .map(|c| Ok(c.get(idx)?.into_static()))
+    .collect::<PolarsJlResult<Vec<_>>>()?;
+  let fields = df.schema().iter_fields().collect();
+  Ok(polars_value_t { inner: AnyValue::StructOwned(Box::new((values, fields))) })
+}
+
+#[cfg(not(feature = "dtype-struct"))]
+fn row_value(_df: &DataFrame, _idx: usize) -> PolarsJlResult<polars_value_t> {
+  Err(PolarsJlError::UnsupportedDataType("Struct".to_string()))
+}
+
+/// The `names` columns of `df`, all columns if empty.
+fn select_subset(df: &DataFrame, names: TypedVector<JuliaString>) -> PolarsJlResult<DataFrame> {
+  let names = extract_strings(names)?;
+  match names.is_empty() {
+    true => Ok(df.clone()),
+    false => Ok(df.select(names)?),
+  }
+}
+
+/// Lazy scan of `path` in `format`, parsing hive partitions from the directory names if `hive`.
+fn scan_files(format: &str, path: PlPath, include_file_paths: Option<PlSmallStr>, hive: bool) -> PolarsJlResult<LazyFrame> {
+  let hive_options = HiveOptions { enabled: Some(hive), ..HiveOptions::default() };
+  let lf = match format {
+    "parquet" => LazyFrame::scan_parquet(path, ScanArgsParquet { hive_options, include_file_paths, ..Default::default() })?,
+    "csv" => LazyCsvReader::new(path).with_include_file_paths(include_file_paths).finish()?,
+    "ipc" => LazyFrame::scan_ipc(path, ScanArgsIpc { hive_options, include_file_paths, ..Default::default() })?,
+    s => return Err(PolarsJlError::OperationError(s.to_string())),
+  };
+  Ok(lf)
+}
+
+/// how: :vertical, :vertical_relaxed, :horizontal, :diagonal, :diagonal_relaxed
+fn concat_how(lfs: Vec<LazyFrame>, how: &str, args: UnionArgs) -> PolarsJlResult<LazyFrame> {
+  let lf = match how {
+    "vertical" => concat(lfs, args),
+    "vertical_relaxed" => concat(lfs, UnionArgs { to_supertypes: true, ..args }),
+    "horizontal" => concat_lf_horizontal(lfs, args),
+    "diagonal" => concat_lf_diagonal(lfs, args),
+    "diagonal_relaxed" => concat_lf_diagonal(lfs, UnionArgs { to_supertypes: true, ..args }),
+    s => return Err(PolarsJlError::ConcatMethodError(s.to_string())),
+  };
+  Ok(lf?)
 }
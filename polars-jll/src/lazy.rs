@@ -0,0 +1,162 @@
+use polars::{io::HiveOptions, prelude::*};
+use jlrs::{data::managed::{ccall_ref::CCallRef, string::StringRet, value::typed::TypedValue}, prelude::*};
+
+use crate::{errors::{catch_panic, PolarsJlResult}, utils::{extract_strings, leak_string, leak_value, CCallRefExt, JuliaValueExt, TypedVecExt}, polars_dataframe_t, DataFrameRet, ExprRef, ExprValue};
+
+/// A query plan over scanned files or frames. Nothing is read until it is collected
+/// or sunk into a file.
+#[derive(OpaqueType)]
+#[allow(non_camel_case_types)]
+pub struct polars_lazyframe_t {
+  pub(crate) inner: LazyFrame,
+}
+
+pub type LazyFrameRet = jlrs::data::managed::ccall_ref::CCallRefRet<polars_lazyframe_t>;
+pub type LazyFrameRef<'scope> = jlrs::data::managed::ccall_ref::CCallRef<'scope, LazyFrameValue<'scope, 'static>>;
+pub type LazyFrameValue<'scope, 'data> = TypedValue<'scope, 'data, polars_lazyframe_t>;
+
+impl std::fmt::Debug for polars_lazyframe_t {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("polars_lazyframe_t").field("plan", &self.inner.describe_plan().ok()).finish()
+  }
+}
+
+impl polars_lazyframe_t {
+  /// Reads `key=value` directories on the path as partition columns, `hive_partitioning`
+  /// is `nothing` to do so only when the path is a directory. Filters on partition columns
+  /// skip the files of other partitions. `include_file_paths` names a column holding the
+  /// path of the file each row comes from, or is `nothing`.
+  pub fn scan_parquet(path: JuliaString, hive_partitioning: CCallRef<Value>, try_parse_hive_dates: bool, include_file_paths: CCallRef<Value>) -> JlrsResult<LazyFrameRet> {
+    catch_panic("polars_lazyframe_scan_parquet", || {
+      let enabled = match hive_partitioning.as_value()?.is::<Nothing>() {
+        true => None,
+        false => Some(hive_partitioning.as_unbox::<bool>()?),
+      };
+      let include_file_paths = match include_file_paths.as_cast_opt::<JuliaString>()? {
+        Some(name) => Some(PlSmallStr::from_str(name.as_str()?)),
+        None => None,
+      };
+      let args = ScanArgsParquet {
+        hive_options: HiveOptions { enabled, try_parse_dates: try_parse_hive_dates, ..HiveOptions::default() },
+        include_file_paths,
+        ..ScanArgsParquet::default()
+      };
+      let lf = LazyFrame::scan_parquet(PlPath::new(path.as_str()?), args)?;
+      Ok(leak_value(Self { inner: lf }))
+    })
+  }
+
+  pub fn scan_csv(path: JuliaString) -> JlrsResult<LazyFrameRet> {
+    catch_panic("polars_lazyframe_scan_csv", || {
+      let lf = LazyCsvReader::new(PlPath::new(path.as_str()?)).finish()?;
+      Ok(leak_value(Self { inner: lf }))
+    })
+  }
+
+  pub fn scan_ipc(path: JuliaString) -> JlrsResult<LazyFrameRet> {
+    catch_panic("polars_lazyframe_scan_ipc", || {
+      let lf = LazyFrame::scan_ipc(PlPath::new(path.as_str()?), ScanArgsIpc::default())?;
+      Ok(leak_value(Self { inner: lf }))
+    })
+  }
+
+  pub fn filter(&self, predicate: ExprRef) -> JlrsResult<LazyFrameRet> {
+    catch_panic("polars_lazyframe_filter", || {
+      let predicate = predicate.tracked_map(|e| e.inner.clone())?;
+      Ok(leak_value(Self { inner: self.inner.clone().filter(predicate) }))
+    })
+  }
+
+  pub fn select(&self, exprs: TypedVector<ExprValue>) -> JlrsResult<LazyFrameRet> {
+    catch_panic("polars_lazyframe_select", || {
+      let exprs = exprs.extract_box(|e| e.inner.clone())?;
+      Ok(leak_value(Self { inner: self.inner.clone().select(exprs) }))
+    })
+  }
+
+  pub fn with_columns(&self, exprs: TypedVector<ExprValue>) -> JlrsResult<LazyFrameRet> {
+    catch_panic("polars_lazyframe_with_columns", || {
+      let exprs = exprs.extract_box(|e| e.inner.clone())?;
+      Ok(leak_value(Self { inner: self.inner.clone().with_columns(exprs) }))
+    })
+  }
+
+  /// One row per unique combination of the `by` columns with the aggregations `aggs`,
+  /// groups are in order of first appearance if `maintain_order`.
+  pub fn group_by(&self, by: TypedVector<JuliaString>, aggs: TypedVector<ExprValue>, maintain_order: bool) -> JlrsResult<LazyFrameRet> {
+    catch_panic("polars_lazyframe_group_by", || {
+      let by = extract_strings(by)?.into_iter().map(col).collect::<Vec<_>>();
+      let aggs = aggs.extract_box(|e| e.inner.clone())?;
+      let gb = match maintain_order {
+        true => self.inner.clone().group_by_stable(by),
+        false => self.inner.clone().group_by(by),
+      };
+      Ok(leak_value(Self { inner: gb.agg(aggs) }))
+    })
+  }
+
+  pub fn sort(&self, by: TypedVector<JuliaString>, descending: bool) -> JlrsResult<LazyFrameRet> {
+    catch_panic("polars_lazyframe_sort", || {
+      let options = SortMultipleOptions::default().with_order_descending(descending).with_maintain_order(true);
+      Ok(leak_value(Self { inner: self.inner.clone().sort(extract_strings(by)?, options) }))
+    })
+  }
+
+  pub fn limit(&self, n: usize) -> JlrsResult<LazyFrameRet> {
+    catch_panic("polars_lazyframe_limit", || {
+      Ok(leak_value(Self { inner: self.inner.clone().limit(n as IdxSize) }))
+    })
+  }
+
+  /// The query plan, after optimizations such as predicate pushdown if `optimized`.
+  pub fn explain(&self, optimized: bool) -> JlrsResult<StringRet> {
+    catch_panic("polars_lazyframe_explain", || {
+      Ok(leak_string(self.inner.explain(optimized)?))
+    })
+  }
+
+  /// Runs the query, on the streaming engine if `streaming`, which processes the
+  /// input in batches instead of loading it at once.
+  pub fn collect(&self, streaming: bool) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_lazyframe_collect", || {
+      let engine = match streaming {
+        true => Engine::Streaming,
+        false => Engine::InMemory,
+      };
+      let df = self.inner.clone().collect_with_engine(engine)?;
+      Ok(leak_value(polars_dataframe_t { inner: df }))
+    })
+  }
+
+  pub fn sink_parquet(&self, path: JuliaString) -> JlrsResult<()> {
+    catch_panic("polars_lazyframe_sink_parquet", || {
+      let lf = self.inner.clone().sink_parquet(sink_target(path)?, ParquetWriteOptions::default(), None, SinkOptions::default())?;
+      run_sink(lf)
+    })
+  }
+
+  pub fn sink_ipc(&self, path: JuliaString) -> JlrsResult<()> {
+    catch_panic("polars_lazyframe_sink_ipc", || {
+      let lf = self.inner.clone().sink_ipc(sink_target(path)?, IpcWriterOptions::default(), None, SinkOptions::default())?;
+      run_sink(lf)
+    })
+  }
+
+  pub fn sink_csv(&self, path: JuliaString) -> JlrsResult<()> {
+    catch_panic("polars_lazyframe_sink_csv", || {
+      let lf = self.inner.clone().sink_csv(sink_target(path)?, CsvWriterOptions::default(), None, SinkOptions::default())?;
+      run_sink(lf)
+    })
+  }
+}
+
+fn sink_target(path: JuliaString) -> PolarsJlResult<SinkTarget> {
+  Ok(SinkTarget::Path(PlPath::new(path.as_str()?)))
+}
+
+/// Sinks always run on the streaming engine, the result is written batch by batch
+/// and never held in memory as a whole.
+fn run_sink(lf: LazyFrame) -> PolarsJlResult<()> {
+  lf.collect_with_engine(Engine::Streaming)?;
+  Ok(())
+}
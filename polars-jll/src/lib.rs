@@ -13,52 +13,195 @@ pub mod columns;
 pub mod frames;
 pub mod values;
 pub mod value_types;
+pub mod strings;
+pub mod temporal;
+pub mod lists;
+pub mod exprs;
+pub mod sql;
+pub mod lazy;
 
 pub use errors::polars_error_t;
 pub use frames::{polars_dataframe_t, DataFrameRef, DataFrameRet, DataFrameValue};
 pub use columns::{polars_column_t, ColumnRef, ColumnRet, ColumnValue};
 pub use value_types::{polars_value_type_t, ValueTypeRef, ValueTypeRet, ValueTypeValue};
 pub use values::{polars_value_t, AnyValueRef, AnyValueRet, AnyValueValue};
+pub use exprs::{polars_expr_t, ExprRef, ExprRet, ExprValue};
+pub use lazy::{polars_lazyframe_t, LazyFrameRef, LazyFrameRet, LazyFrameValue};
+pub use sql::{polars_sql_context_t, SqlContextRef, SqlContextRet, SqlContextValue};
 
 julia_module!{
   become julia_module_polars_init_fn;
 
-  fn polars_version() -> StringRet;
+  fn polars_version() -> JlrsResult<StringRet>;
 
   struct polars_error_t;
-  in polars_error_t fn message(&self) -> StringRet as polars_error_message;
+  in polars_error_t fn message(&self) -> JlrsResult<StringRet> as polars_error_message;
+  in polars_error_t fn kind(&self) -> JlrsResult<SymbolRet> as polars_error_kind;
+  in polars_error_t fn function(&self) -> JlrsResult<StringRet> as polars_error_function;
+  in polars_error_t fn take_last() -> JlrsResult<ValueRet> as polars_error_take_last;
 
   struct polars_dataframe_t;
-  in polars_dataframe_t fn new_empty() -> DataFrameRet as polars_dataframe_new_empty;
+  in polars_dataframe_t fn new_empty() -> JlrsResult<DataFrameRet> as polars_dataframe_new_empty;
   in polars_dataframe_t fn from_cols(cols: TypedVector<ColumnValue>) -> JlrsResult<DataFrameRet> as polars_dataframe_from_cols;
-  in polars_dataframe_t fn height(&self) -> usize as polars_dataframe_height;
+  in polars_dataframe_t fn concat(dfs: TypedVector<DataFrameValue>, how: CCallRef<Symbol>, rechunk: bool) -> JlrsResult<DataFrameRet> as polars_dataframe_concat;
+  in polars_dataframe_t fn height(&self) -> JlrsResult<usize> as polars_dataframe_height;
   in polars_dataframe_t fn read_parquet(path: JuliaString) -> JlrsResult<DataFrameRet> as polars_dataframe_read_parquet;
+  in polars_dataframe_t fn read_files(paths: TypedVector<JuliaString>, format: CCallRef<Symbol>, how: CCallRef<Symbol>, include_file_paths: CCallRef<Value>) -> JlrsResult<DataFrameRet> as polars_dataframe_read_files;
   in polars_dataframe_t fn write_parquet(&mut self, path: JuliaString) -> JlrsResult<()> as polars_dataframe_write_parquet;
+  in polars_dataframe_t fn write_parquet_partitioned(&mut self, path: JuliaString, partition_by: TypedVector<JuliaString>, chunk_size: u64) -> JlrsResult<()> as polars_dataframe_write_parquet_partitioned;
   in polars_dataframe_t fn show(&self, io: CCallRef<IO>) -> JlrsResult<()> as polars_dataframe_show;
   in polars_dataframe_t fn get_column(&self, name: JuliaString) -> JlrsResult<ColumnRet> as polars_dataframe_get_column;
+  in polars_dataframe_t fn cast(&self, names: TypedVector<JuliaString>, dtypes: TypedVector<ValueTypeValue>, strict: bool) -> JlrsResult<DataFrameRet> as polars_dataframe_cast;
+  in polars_dataframe_t fn explode(&self, names: TypedVector<JuliaString>) -> JlrsResult<DataFrameRet> as polars_dataframe_explode;
+  in polars_dataframe_t fn implode(&self) -> JlrsResult<DataFrameRet> as polars_dataframe_implode;
+  in polars_dataframe_t fn lazy(&self) -> JlrsResult<LazyFrameRet> as polars_dataframe_lazy;
+  in polars_dataframe_t fn unique(&self, subset: TypedVector<JuliaString>, keep: CCallRef<Symbol>, maintain_order: bool) -> JlrsResult<DataFrameRet> as polars_dataframe_unique;
+  in polars_dataframe_t fn is_duplicated(&self, subset: TypedVector<JuliaString>) -> JlrsResult<ColumnRet> as polars_dataframe_is_duplicated;
+  in polars_dataframe_t fn is_unique(&self, subset: TypedVector<JuliaString>) -> JlrsResult<ColumnRet> as polars_dataframe_is_unique;
+  in polars_dataframe_t fn n_unique(&self, subset: TypedVector<JuliaString>) -> JlrsResult<usize> as polars_dataframe_n_unique;
+  in polars_dataframe_t fn drop_nulls(&self, subset: TypedVector<JuliaString>) -> JlrsResult<DataFrameRet> as polars_dataframe_drop_nulls;
+  in polars_dataframe_t fn pivot(&self, on: TypedVector<JuliaString>, index: TypedVector<JuliaString>, values: TypedVector<JuliaString>, aggregate_function: CCallRef<Value>, sort_columns: bool, separator: JuliaString) -> JlrsResult<DataFrameRet> as polars_dataframe_pivot;
+  in polars_dataframe_t fn unpivot(&self, on: TypedVector<JuliaString>, index: TypedVector<JuliaString>, variable_name: JuliaString, value_name: JuliaString) -> JlrsResult<DataFrameRet> as polars_dataframe_unpivot;
+  in polars_dataframe_t fn select(&self, exprs: TypedVector<ExprValue>) -> JlrsResult<DataFrameRet> as polars_dataframe_select;
+  in polars_dataframe_t fn with_columns(&self, exprs: TypedVector<ExprValue>) -> JlrsResult<DataFrameRet> as polars_dataframe_with_columns;
+  in polars_dataframe_t fn rolling(&self, index_column: JuliaString, period: JuliaString, offset: JuliaString, closed: CCallRef<Symbol>, group_by: TypedVector<JuliaString>, aggs: TypedVector<ExprValue>) -> JlrsResult<DataFrameRet> as polars_dataframe_rolling;
+  in polars_dataframe_t fn group_by_dynamic(&self, index_column: JuliaString, every: JuliaString, period: JuliaString, offset: JuliaString, closed: CCallRef<Symbol>, label: CCallRef<Symbol>, start_by: CCallRef<Symbol>, include_boundaries: bool, group_by: TypedVector<JuliaString>, aggs: TypedVector<ExprValue>) -> JlrsResult<DataFrameRet> as polars_dataframe_group_by_dynamic;
+  in polars_dataframe_t fn get_row(&self, idx: usize) -> JlrsResult<AnyValueRet> as polars_dataframe_get_row;
+  in polars_dataframe_t fn row_by_predicate(&self, mask: ColumnRef) -> JlrsResult<AnyValueRet> as polars_dataframe_row_by_predicate;
+  in polars_dataframe_t fn rows(&self, offset: usize, n: usize) -> JlrsResult<ValueRet> as polars_dataframe_rows;
 
   struct polars_column_t;
   in polars_column_t fn new_empty(name: JuliaString, dtype: ValueTypeRef) -> JlrsResult<ColumnRet> as polars_column_new_empty;
-  in polars_column_t fn len(&self) -> usize as polars_column_len;
-  in polars_column_t fn dtype(&self) -> ValueTypeRet as polars_column_dtype;
-  in polars_column_t fn name(&self) -> StringRet as polars_column_name;
-  in polars_column_t fn null_count(&self) -> usize as polars_column_null_count;
-  in polars_column_t fn is_null(&self, idx: usize) -> bool as polars_column_is_null;
+  in polars_column_t fn from_bits(name: JuliaString, dtype: ValueTypeRef, data: TypedVector<u8>, validity: TypedVector<bool>) -> JlrsResult<ColumnRet> as polars_column_from_bits;
+  in polars_column_t fn from_bytes(name: JuliaString, dtype: ValueTypeRef, data: TypedVector<u8>, offsets: TypedVector<i64>, validity: TypedVector<bool>) -> JlrsResult<ColumnRet> as polars_column_from_bytes;
+  in polars_column_t fn from_list(name: JuliaString, values: ColumnRef, offsets: TypedVector<i64>, validity: TypedVector<bool>) -> JlrsResult<ColumnRet> as polars_column_from_list;
+  in polars_column_t fn len(&self) -> JlrsResult<usize> as polars_column_len;
+  in polars_column_t fn dtype(&self) -> JlrsResult<ValueTypeRet> as polars_column_dtype;
+  in polars_column_t fn name(&self) -> JlrsResult<StringRet> as polars_column_name;
+  in polars_column_t fn null_count(&self) -> JlrsResult<usize> as polars_column_null_count;
+  in polars_column_t fn is_null(&self, idx: usize) -> JlrsResult<bool> as polars_column_is_null;
   in polars_column_t fn get(&self, idx: usize) -> JlrsResult<AnyValueRet> as polars_column_get;
+  in polars_column_t fn to_vector(&self) -> JlrsResult<ValueRet> as polars_column_to_vector;
+  in polars_column_t fn list_offsets(&self) -> JlrsResult<ValueRet> as polars_column_list_offsets;
+  in polars_column_t fn list_values(&self) -> JlrsResult<ColumnRet> as polars_column_list_values;
+  in polars_column_t fn list_len(&self) -> JlrsResult<ColumnRet> as polars_column_list_len;
+  in polars_column_t fn list_get(&self, index: i64, null_on_oob: bool) -> JlrsResult<ColumnRet> as polars_column_list_get;
+  in polars_column_t fn list_slice(&self, offset: i64, length: CCallRef<Value>) -> JlrsResult<ColumnRet> as polars_column_list_slice;
+  in polars_column_t fn list_contains(&self, item: AnyValueRef) -> JlrsResult<ColumnRet> as polars_column_list_contains;
+  in polars_column_t fn list_reduce(&self, op: CCallRef<Symbol>) -> JlrsResult<ColumnRet> as polars_column_list_reduce;
+  in polars_column_t fn list_sort(&self, descending: bool, nulls_last: bool) -> JlrsResult<ColumnRet> as polars_column_list_sort;
+  in polars_column_t fn list_unique(&self, maintain_order: bool) -> JlrsResult<ColumnRet> as polars_column_list_unique;
+  in polars_column_t fn list_join(&self, separator: JuliaString, ignore_nulls: bool) -> JlrsResult<ColumnRet> as polars_column_list_join;
+  in polars_column_t fn list_eval(&self, expr: ExprRef) -> JlrsResult<ColumnRet> as polars_column_list_eval;
+  in polars_column_t fn list_with_values(&self, values: ColumnRef) -> JlrsResult<ColumnRet> as polars_column_list_with_values;
+  in polars_column_t fn implode(&self) -> JlrsResult<ColumnRet> as polars_column_implode;
+  in polars_column_t fn binary(&self, other: ColumnRef, op: CCallRef<Symbol>) -> JlrsResult<ColumnRet> as polars_column_binary;
+  in polars_column_t fn reduce(&self, op: CCallRef<Symbol>) -> JlrsResult<AnyValueRet> as polars_column_reduce;
+  in polars_column_t fn std(&self, ddof: u8) -> JlrsResult<AnyValueRet> as polars_column_std;
+  in polars_column_t fn var(&self, ddof: u8) -> JlrsResult<AnyValueRet> as polars_column_var;
+  in polars_column_t fn quantile(&self, quantile: f64, method: CCallRef<Symbol>) -> JlrsResult<AnyValueRet> as polars_column_quantile;
+  in polars_column_t fn is_duplicated(&self) -> JlrsResult<ColumnRet> as polars_column_is_duplicated;
+  in polars_column_t fn is_unique(&self) -> JlrsResult<ColumnRet> as polars_column_is_unique;
+  in polars_column_t fn value_counts(&self, sort: bool, normalize: bool, name: JuliaString) -> JlrsResult<DataFrameRet> as polars_column_value_counts;
+  in polars_column_t fn fill_null(&self, value: AnyValueRef) -> JlrsResult<ColumnRet> as polars_column_fill_null;
+  in polars_column_t fn fill_null_with_strategy(&self, strategy: CCallRef<Symbol>, limit: CCallRef<Value>) -> JlrsResult<ColumnRet> as polars_column_fill_null_with_strategy;
+  in polars_column_t fn fill_nan(&self, value: AnyValueRef) -> JlrsResult<ColumnRet> as polars_column_fill_nan;
+  in polars_column_t fn is_nan(&self) -> JlrsResult<ColumnRet> as polars_column_is_nan;
+  in polars_column_t fn interpolate(&self, method: CCallRef<Symbol>) -> JlrsResult<ColumnRet> as polars_column_interpolate;
+  in polars_column_t fn cast(&self, dtype: ValueTypeRef, strict: bool) -> JlrsResult<ColumnRet> as polars_column_cast;
+  in polars_column_t fn str_contains(&self, pattern: JuliaString, literal: bool) -> JlrsResult<ColumnRet> as polars_column_str_contains;
+  in polars_column_t fn str_starts_with(&self, prefix: JuliaString) -> JlrsResult<ColumnRet> as polars_column_str_starts_with;
+  in polars_column_t fn str_ends_with(&self, suffix: JuliaString) -> JlrsResult<ColumnRet> as polars_column_str_ends_with;
+  in polars_column_t fn str_replace(&self, pattern: JuliaString, value: JuliaString, literal: bool, all: bool) -> JlrsResult<ColumnRet> as polars_column_str_replace;
+  in polars_column_t fn str_split(&self, by: JuliaString, inclusive: bool) -> JlrsResult<ColumnRet> as polars_column_str_split;
+  in polars_column_t fn str_extract(&self, pattern: JuliaString, group: usize) -> JlrsResult<ColumnRet> as polars_column_str_extract;
+  in polars_column_t fn str_extract_all(&self, pattern: JuliaString) -> JlrsResult<ColumnRet> as polars_column_str_extract_all;
+  in polars_column_t fn str_extract_groups(&self, pattern: JuliaString) -> JlrsResult<ColumnRet> as polars_column_str_extract_groups;
+  in polars_column_t fn str_to_lowercase(&self) -> JlrsResult<ColumnRet> as polars_column_str_to_lowercase;
+  in polars_column_t fn str_to_uppercase(&self) -> JlrsResult<ColumnRet> as polars_column_str_to_uppercase;
+  in polars_column_t fn str_strip_chars(&self, chars: CCallRef<Value>, side: CCallRef<Symbol>) -> JlrsResult<ColumnRet> as polars_column_str_strip_chars;
+  in polars_column_t fn str_len_bytes(&self) -> JlrsResult<ColumnRet> as polars_column_str_len_bytes;
+  in polars_column_t fn str_len_chars(&self) -> JlrsResult<ColumnRet> as polars_column_str_len_chars;
+  in polars_column_t fn str_slice(&self, offset: i64, length: CCallRef<Value>) -> JlrsResult<ColumnRet> as polars_column_str_slice;
+  in polars_column_t fn str_zfill(&self, length: usize) -> JlrsResult<ColumnRet> as polars_column_str_zfill;
+  in polars_column_t fn str_strptime(&self, dtype: ValueTypeRef, format: CCallRef<Value>, strict: bool) -> JlrsResult<ColumnRet> as polars_column_str_strptime;
+  in polars_column_t fn str_json_decode(&self, dtype: CCallRef<Value>) -> JlrsResult<ColumnRet> as polars_column_str_json_decode;
+  in polars_column_t fn dt_component(&self, component: CCallRef<Symbol>) -> JlrsResult<ColumnRet> as polars_column_dt_component;
+  in polars_column_t fn dt_truncate(&self, every: JuliaString) -> JlrsResult<ColumnRet> as polars_column_dt_truncate;
+  in polars_column_t fn dt_round(&self, every: JuliaString) -> JlrsResult<ColumnRet> as polars_column_dt_round;
+  in polars_column_t fn dt_offset_by(&self, by: JuliaString) -> JlrsResult<ColumnRet> as polars_column_dt_offset_by;
+  in polars_column_t fn dt_epoch(&self, unit: CCallRef<Symbol>) -> JlrsResult<ColumnRet> as polars_column_dt_epoch;
+  in polars_column_t fn dt_strftime(&self, format: JuliaString) -> JlrsResult<ColumnRet> as polars_column_dt_strftime;
+  in polars_column_t fn dt_total(&self, unit: CCallRef<Symbol>) -> JlrsResult<ColumnRet> as polars_column_dt_total;
+  in polars_column_t fn cast_time_unit(&self, unit: CCallRef<Symbol>) -> JlrsResult<ColumnRet> as polars_column_cast_time_unit;
+  in polars_column_t fn convert_time_zone(&self, time_zone: JuliaString) -> JlrsResult<ColumnRet> as polars_column_convert_time_zone;
+  in polars_column_t fn replace_time_zone(&self, time_zone: CCallRef<Value>) -> JlrsResult<ColumnRet> as polars_column_replace_time_zone;
+  in polars_column_t fn categorical_parts(&self) -> JlrsResult<ValueRet> as polars_column_categorical_parts;
+  in polars_column_t fn struct_width(&self) -> JlrsResult<usize> as polars_column_struct_width;
+  in polars_column_t fn struct_field(&self, idx: usize) -> JlrsResult<ColumnRet> as polars_column_struct_field;
 
   struct polars_value_type_t;
-  in polars_value_type_t fn display(&self) -> StringRet as polars_value_type_display;
-  in polars_value_type_t fn symbol(&self) -> SymbolRet as polars_value_type_symbol;
+  in polars_value_type_t fn display(&self) -> JlrsResult<StringRet> as polars_value_type_display;
+  in polars_value_type_t fn symbol(&self) -> JlrsResult<SymbolRet> as polars_value_type_symbol;
   // this is actually JlrsResult<NamedTupleRet>
   // https://github.com/Taaitaaiger/jlrs/issues/197
   in polars_value_type_t fn kwargs(&self) -> JlrsResult<ValueRet> as polars_value_type_kwargs;
   in polars_value_type_t fn from_name_and_kwargs(name: CCallRef<Symbol>, kwargs: CCallRef<NamedTuple<'_, 'static>>) -> JlrsResult<ValueTypeRet> as polars_value_type_from_name_and_kwargs;
 
   struct polars_value_t;
-  in polars_value_t fn dtype(&self) -> ValueTypeRet as polars_value_dtype;
+  in polars_value_t fn new_null() -> JlrsResult<AnyValueRet> as polars_value_new_null;
+  in polars_value_t fn from_bits(dtype: ValueTypeRef, hi: i64, lo: u64) -> JlrsResult<AnyValueRet> as polars_value_from_bits;
+  in polars_value_t fn new_string(value: JuliaString) -> JlrsResult<AnyValueRet> as polars_value_new_string;
+  in polars_value_t fn new_binary(value: TypedVector<u8>) -> JlrsResult<AnyValueRet> as polars_value_new_binary;
+  in polars_value_t fn new_list(values: TypedVector<AnyValueValue>) -> JlrsResult<AnyValueRet> as polars_value_new_list;
+  in polars_value_t fn new_struct(names: TypedVector<JuliaString>, values: TypedVector<AnyValueValue>) -> JlrsResult<AnyValueRet> as polars_value_new_struct;
+  in polars_value_t fn dtype(&self) -> JlrsResult<ValueTypeRet> as polars_value_dtype;
   in polars_value_t fn extract(&self) -> JlrsResult<ValueRet> as polars_value_extract;
+  in polars_value_t fn struct_len(&self) -> JlrsResult<usize> as polars_value_struct_len;
+  in polars_value_t fn struct_field_name(&self, idx: usize) -> JlrsResult<SymbolRet> as polars_value_struct_field_name;
+  in polars_value_t fn struct_field(&self, idx: usize) -> JlrsResult<AnyValueRet> as polars_value_struct_field;
+
+  struct polars_expr_t;
+  in polars_expr_t fn col(name: JuliaString) -> JlrsResult<ExprRet> as polars_expr_col;
+  in polars_expr_t fn lit(value: AnyValueRef) -> JlrsResult<ExprRet> as polars_expr_lit;
+  in polars_expr_t fn lit_dyn(value: AnyValueRef) -> JlrsResult<ExprRet> as polars_expr_lit_dyn;
+  in polars_expr_t fn display(&self) -> JlrsResult<StringRet> as polars_expr_display;
+  in polars_expr_t fn neg(&self) -> JlrsResult<ExprRet> as polars_expr_neg;
+  in polars_expr_t fn alias(&self, name: JuliaString) -> JlrsResult<ExprRet> as polars_expr_alias;
+  in polars_expr_t fn binary(&self, other: ExprRef, op: CCallRef<Symbol>) -> JlrsResult<ExprRet> as polars_expr_binary;
+  in polars_expr_t fn agg(&self, op: CCallRef<Symbol>) -> JlrsResult<ExprRet> as polars_expr_agg;
+  in polars_expr_t fn cum(&self, op: CCallRef<Symbol>, reverse: bool) -> JlrsResult<ExprRet> as polars_expr_cum;
+  in polars_expr_t fn rank(&self, method: CCallRef<Symbol>, descending: bool) -> JlrsResult<ExprRet> as polars_expr_rank;
+  in polars_expr_t fn shift(&self, n: i64) -> JlrsResult<ExprRet> as polars_expr_shift;
+  in polars_expr_t fn diff(&self, n: i64) -> JlrsResult<ExprRet> as polars_expr_diff;
+  in polars_expr_t fn pct_change(&self, n: i64) -> JlrsResult<ExprRet> as polars_expr_pct_change;
+  in polars_expr_t fn over(&self, partition_by: TypedVector<ExprValue>, order_by: TypedVector<ExprValue>, descending: bool) -> JlrsResult<ExprRet> as polars_expr_over;
+
+  struct polars_lazyframe_t;
+  in polars_lazyframe_t fn scan_parquet(path: JuliaString, hive_partitioning: CCallRef<Value>, try_parse_hive_dates: bool, include_file_paths: CCallRef<Value>) -> JlrsResult<LazyFrameRet> as polars_lazyframe_scan_parquet;
+  in polars_lazyframe_t fn scan_csv(path: JuliaString) -> JlrsResult<LazyFrameRet> as polars_lazyframe_scan_csv;
+  in polars_lazyframe_t fn scan_ipc(path: JuliaString) -> JlrsResult<LazyFrameRet> as polars_lazyframe_scan_ipc;
+  in polars_lazyframe_t fn filter(&self, predicate: ExprRef) -> JlrsResult<LazyFrameRet> as polars_lazyframe_filter;
+  in polars_lazyframe_t fn select(&self, exprs: TypedVector<ExprValue>) -> JlrsResult<LazyFrameRet> as polars_lazyframe_select;
+  in polars_lazyframe_t fn with_columns(&self, exprs: TypedVector<ExprValue>) -> JlrsResult<LazyFrameRet> as polars_lazyframe_with_columns;
+  in polars_lazyframe_t fn group_by(&self, by: TypedVector<JuliaString>, aggs: TypedVector<ExprValue>, maintain_order: bool) -> JlrsResult<LazyFrameRet> as polars_lazyframe_group_by;
+  in polars_lazyframe_t fn sort(&self, by: TypedVector<JuliaString>, descending: bool) -> JlrsResult<LazyFrameRet> as polars_lazyframe_sort;
+  in polars_lazyframe_t fn limit(&self, n: usize) -> JlrsResult<LazyFrameRet> as polars_lazyframe_limit;
+  in polars_lazyframe_t fn explain(&self, optimized: bool) -> JlrsResult<StringRet> as polars_lazyframe_explain;
+  in polars_lazyframe_t fn collect(&self, streaming: bool) -> JlrsResult<DataFrameRet> as polars_lazyframe_collect;
+  in polars_lazyframe_t fn sink_parquet(&self, path: JuliaString) -> JlrsResult<()> as polars_lazyframe_sink_parquet;
+  in polars_lazyframe_t fn sink_ipc(&self, path: JuliaString) -> JlrsResult<()> as polars_lazyframe_sink_ipc;
+  in polars_lazyframe_t fn sink_csv(&self, path: JuliaString) -> JlrsResult<()> as polars_lazyframe_sink_csv;
+
+  struct polars_sql_context_t;
+  in polars_sql_context_t fn new() -> JlrsResult<SqlContextRet> as polars_sql_context_new;
+  in polars_sql_context_t fn register(&self, name: JuliaString, df: DataFrameRef) -> JlrsResult<()> as polars_sql_context_register;
+  in polars_sql_context_t fn register_lazy(&self, name: JuliaString, lf: LazyFrameRef) -> JlrsResult<()> as polars_sql_context_register_lazy;
+  in polars_sql_context_t fn unregister(&self, name: JuliaString) -> JlrsResult<()> as polars_sql_context_unregister;
+  in polars_sql_context_t fn tables(&self) -> JlrsResult<ValueRet> as polars_sql_context_tables;
+  in polars_sql_context_t fn execute(&self, query: JuliaString) -> JlrsResult<DataFrameRet> as polars_sql_context_execute;
 }
 
-pub fn polars_version() -> StringRet {
-  utils::leak_string(polars::VERSION)
+pub fn polars_version() -> JlrsResult<StringRet> {
+  errors::catch_panic("polars_version", || Ok(utils::leak_string(polars::VERSION)))
 }
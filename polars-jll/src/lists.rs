@@ -0,0 +1,135 @@
+use polars::prelude::*;
+use jlrs::{data::managed::ccall_ref::CCallRef, prelude::*};
+
+use crate::{errors::{catch_panic, PolarsJlError, PolarsJlResult}, polars_column_t, utils::{leak_value, CCallRefExt, JuliaValueExt}, AnyValueRef, ColumnRef, ColumnRet, ExprRef};
+
+/// Kernels of the polars `list` namespace. Array columns are cast to List first,
+/// so results that are still nested are List columns.
+impl polars_column_t {
+  pub fn list_len(&self) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_list_len", || {
+      self.list_apply(|ca| Ok(ca.lst_lengths()))
+    })
+  }
+
+  /// Element `index` (0-based, negative counts from the end) of every list.
+  /// Out of bounds indices are null if `null_on_oob`, otherwise an error.
+  pub fn list_get(&self, index: i64, null_on_oob: bool) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_list_get", || {
+      self.list_apply(|ca| ca.lst_get(index, null_on_oob))
+    })
+  }
+
+  /// `length` elements from the 0-based `offset`, a `nothing` length takes the rest.
+  pub fn list_slice(&self, offset: i64, length: CCallRef<Value>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_list_slice", || {
+      let length = match length.as_value()?.is::<Nothing>() {
+        true => usize::MAX,
+        false => length.as_unbox::<u64>()? as usize,
+      };
+      self.list_apply(|ca| Ok(ca.lst_slice(offset, length)))
+    })
+  }
+
+  /// `item` is cast to the element type, a null item matches null elements.
+  pub fn list_contains(&self, item: AnyValueRef) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_list_contains", || {
+      let item = item.tracked_map(|v| v.inner.clone())?;
+      let item = Scalar::new(item.dtype(), item).into_series(PlSmallStr::EMPTY);
+      self.list_apply(|ca| is_in(&item.strict_cast(ca.inner_dtype())?, &ca.clone().into_series(), true))
+    })
+  }
+
+  /// Reduces every list to one value.
+  /// op: :sum, :mean, :min, :max, :median, :n_unique, :first, :last
+  pub fn list_reduce(&self, op: CCallRef<Symbol>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_list_reduce", || {
+      let f: fn(&ListChunked) -> PolarsResult<Series> = match op.as_managed()?.as_str()? {
+        "sum" => |ca| ca.lst_sum(),
+        "mean" => |ca| Ok(ca.lst_mean()),
+        "min" => |ca| ca.lst_min(),
+        "max" => |ca| ca.lst_max(),
+        "median" => |ca| Ok(ca.lst_median()),
+        "n_unique" => |ca| Ok(ca.lst_n_unique()?.into_series()),
+        "first" => |ca| ca.lst_get(0, true),
+        "last" => |ca| ca.lst_get(-1, true),
+        op => return Err(PolarsJlError::OperationError(op.to_string()))?,
+      };
+      self.list_apply(f)
+    })
+  }
+
+  pub fn list_sort(&self, descending: bool, nulls_last: bool) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_list_sort", || {
+      let options = SortOptions { descending, nulls_last, ..Default::default() };
+      self.list_apply(|ca| ca.lst_sort(options))
+    })
+  }
+
+  /// Distinct elements of every list, in order of appearance if `maintain_order`.
+  pub fn list_unique(&self, maintain_order: bool) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_list_unique", || {
+      self.list_apply(|ca| match maintain_order {
+        true => ca.lst_unique_stable(),
+        false => ca.lst_unique(),
+      })
+    })
+  }
+
+  /// Concatenates the strings of every list with `separator` in between.
+  pub fn list_join(&self, separator: JuliaString, ignore_nulls: bool) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_list_join", || {
+      let separator = StringChunked::from_slice(PlSmallStr::EMPTY, &[separator.as_str()?]);
+      self.list_apply(|ca| ca.lst_join(&separator, ignore_nulls))
+    })
+  }
+
+  /// Evaluates `expr` on every list, `Exprs.element()` refers to the elements of the list.
+  pub fn list_eval(&self, expr: ExprRef) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_list_eval", || {
+      let expr = expr.tracked_map(|e| e.inner.clone())?;
+      // list.eval only exists as an expression, so it runs as a query on a frame of the lists
+      let list = self.as_list()?;
+      let name = list.name().clone();
+      let df = DataFrame::new(vec![list])?.lazy().select([col(name.clone()).list().eval(expr).alias(name)]).collect()?;
+      Ok(leak_value(Self { inner: df.get_columns()[0].clone() }))
+    })
+  }
+
+  /// The same lists with their flattened values (see `list_values`) replaced by `values`,
+  /// which must have the same length. Array columns stay Array columns.
+  pub fn list_with_values(&self, values: ColumnRef) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_list_with_values", || {
+      let values = values.tracked_map(|c| c.inner.as_materialized_series().clone())?;
+      let s = match self.inner.dtype() {
+        DataType::List(_) => self.inner.list()?.apply_to_inner(&|_| Ok(values.clone()))?.into_series(),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(_, _) => self.inner.array()?.apply_to_inner(&|_| Ok(values.clone()))?.into_series(),
+        dt => return Err(PolarsJlError::UnsupportedDataType(dt.to_string()))?,
+      };
+      Ok(leak_value(Self { inner: s.into_column() }))
+    })
+  }
+
+  /// All values as a List column of length 1.
+  pub fn implode(&self) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_implode", || {
+      Ok(leak_value(Self { inner: self.inner.implode()?.into_series().into_column() }))
+    })
+  }
+
+  /// Runs `f` on the lists, the result keeps the column's name.
+  fn list_apply<C: IntoColumn>(&self, f: impl FnOnce(&ListChunked) -> PolarsResult<C>) -> PolarsJlResult<ColumnRet> {
+    let out = f(self.as_list()?.list()?)?.into_column();
+    Ok(leak_value(Self { inner: out.with_name(self.inner.name().clone()) }))
+  }
+
+  fn as_list(&self) -> PolarsJlResult<Column> {
+    match self.inner.dtype() {
+      DataType::List(_) => Ok(self.inner.clone()),
+      #[cfg(feature = "dtype-array")]
+      DataType::Array(inner, _) => Ok(self.inner.cast(&DataType::List(inner.clone()))?),
+      dt => Err(PolarsJlError::UnsupportedDataType(dt.to_string())),
+    }
+  }
+}
@@ -0,0 +1,81 @@
+use std::sync::Mutex;
+
+use polars::{prelude::*, sql::SQLContext};
+use jlrs::{data::managed::value::{typed::TypedValue, ValueRet}, prelude::*, weak_handle};
+
+use crate::{errors::{catch_panic, PolarsJlError}, utils::{jl_string_vector, leak_value, CCallRefExt}, polars_dataframe_t, DataFrameRef, DataFrameRet, LazyFrameRef};
+
+/// Frames registered under table names, queried with SQL.
+#[derive(OpaqueType)]
+#[allow(non_camel_case_types)]
+pub struct polars_sql_context_t {
+  // the context keeps planning state in RefCells, the mutex makes it safe to share
+  pub(crate) inner: Mutex<SQLContext>,
+}
+
+pub type SqlContextRet = jlrs::data::managed::ccall_ref::CCallRefRet<polars_sql_context_t>;
+pub type SqlContextRef<'scope> = jlrs::data::managed::ccall_ref::CCallRef<'scope, SqlContextValue<'scope, 'static>>;
+pub type SqlContextValue<'scope, 'data> = TypedValue<'scope, 'data, polars_sql_context_t>;
+
+impl std::fmt::Debug for polars_sql_context_t {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("polars_sql_context_t").field("tables", &self.context().get_tables()).finish()
+  }
+}
+
+impl polars_sql_context_t {
+  pub fn new() -> JlrsResult<SqlContextRet> {
+    catch_panic("polars_sql_context_new", || {
+      Ok(leak_value(Self { inner: Mutex::new(SQLContext::new()) }))
+    })
+  }
+
+  /// Registers `df` as table `name`, replacing a table of the same name.
+  pub fn register(&self, name: JuliaString, df: DataFrameRef) -> JlrsResult<()> {
+    catch_panic("polars_sql_context_register", || {
+      let lf = df.tracked_map(|df| df.inner.clone().lazy())?;
+      self.context().register(name.as_str()?, lf);
+      Ok(())
+    })
+  }
+
+  /// Registers the query `lf` as table `name`, it runs as part of every query using it.
+  pub fn register_lazy(&self, name: JuliaString, lf: LazyFrameRef) -> JlrsResult<()> {
+    catch_panic("polars_sql_context_register_lazy", || {
+      let lf = lf.tracked_map(|lf| lf.inner.clone())?;
+      self.context().register(name.as_str()?, lf);
+      Ok(())
+    })
+  }
+
+  pub fn unregister(&self, name: JuliaString) -> JlrsResult<()> {
+    catch_panic("polars_sql_context_unregister", || {
+      self.context().unregister(name.as_str()?);
+      Ok(())
+    })
+  }
+
+  /// The names of the registered tables, sorted.
+  pub fn tables(&self) -> JlrsResult<ValueRet> {
+    catch_panic("polars_sql_context_tables", || {
+      let tables = self.context().get_tables();
+      match weak_handle!() {
+        Ok(handle) => jl_string_vector(&handle, tables.iter().map(|t| Some(t.as_str()))),
+        Err(_) => Err(PolarsJlError::WeakHandleError("polars_sql_context_t::tables"))?,
+      }
+    })
+  }
+
+  /// Runs `query` against the registered tables and collects the result.
+  pub fn execute(&self, query: JuliaString) -> JlrsResult<DataFrameRet> {
+    catch_panic("polars_sql_context_execute", || {
+      let lf = self.context().execute(query.as_str()?)?;
+      Ok(leak_value(polars_dataframe_t { inner: lf.collect()? }))
+    })
+  }
+
+  fn context(&self) -> std::sync::MutexGuard<'_, SQLContext> {
+    // a panic while holding the lock leaves the table map intact
+    self.inner.lock().unwrap_or_else(|e| e.into_inner())
+  }
+}
@@ -0,0 +1,207 @@
+use polars::{polars_utils::regex_cache::compile_regex, prelude::*};
+use jlrs::{data::managed::ccall_ref::CCallRef, prelude::*};
+
+use crate::{errors::{catch_panic, PolarsJlError, PolarsJlResult}, polars_column_t, polars_value_type_t, utils::{leak_value, CCallRefExt, JuliaValueExt}, ColumnRet, ValueTypeRef};
+
+/// Kernels of the polars `str` namespace, they all require a String column.
+impl polars_column_t {
+  /// `pattern` is a regex unless `literal` is set.
+  pub fn str_contains(&self, pattern: JuliaString, literal: bool) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_contains", || {
+      let pattern = pattern.as_str()?;
+      self.str_apply(|ca| match literal {
+        true => ca.contains_literal(pattern),
+        false => ca.contains(pattern, true),
+      })
+    })
+  }
+
+  pub fn str_starts_with(&self, prefix: JuliaString) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_starts_with", || {
+      let prefix = prefix.as_str()?;
+      self.str_apply(|ca| Ok(ca.as_binary().starts_with(prefix.as_bytes())))
+    })
+  }
+
+  pub fn str_ends_with(&self, suffix: JuliaString) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_ends_with", || {
+      let suffix = suffix.as_str()?;
+      self.str_apply(|ca| Ok(ca.as_binary().ends_with(suffix.as_bytes())))
+    })
+  }
+
+  /// Replaces the first match of `pattern`, or every match when `all` is set.
+  /// `value` may refer to capture groups as `$1` unless `literal` is set.
+  pub fn str_replace(&self, pattern: JuliaString, value: JuliaString, literal: bool, all: bool) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_replace", || {
+      let (pattern, value) = (pattern.as_str()?, value.as_str()?);
+      self.str_apply(|ca| match (all, literal) {
+        (true, true) => ca.replace_literal_all(pattern, value),
+        (true, false) => ca.replace_all(pattern, value),
+        (false, true) => ca.replace_literal(pattern, value, 1),
+        (false, false) => ca.replace(pattern, value),
+      })
+    })
+  }
+
+  /// List of the parts between `by`, kept at the end of each part when `inclusive` is set.
+  pub fn str_split(&self, by: JuliaString, inclusive: bool) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_split", || {
+      let by = StringChunked::from_slice(PlSmallStr::EMPTY, &[by.as_str()?]);
+      self.str_apply(|ca| match inclusive {
+        true => ca.split_inclusive(&by),
+        false => ca.split(&by),
+      })
+    })
+  }
+
+  /// Capture group `group` of the first match, 0 is the whole match.
+  pub fn str_extract(&self, pattern: JuliaString, group: usize) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_extract", || {
+      let pattern = StringChunked::from_slice(PlSmallStr::EMPTY, &[pattern.as_str()?]);
+      self.str_apply(|ca| ca.extract(&pattern, group))
+    })
+  }
+
+  /// List of all matches of `pattern`.
+  pub fn str_extract_all(&self, pattern: JuliaString) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_extract_all", || {
+      let pattern = pattern.as_str()?;
+      self.str_apply(|ca| ca.extract_all(pattern))
+    })
+  }
+
+  /// Struct with a field per capture group of the first match, named groups keep their name.
+  pub fn str_extract_groups(&self, pattern: JuliaString) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_extract_groups", || {
+      let pattern = pattern.as_str()?;
+      // a String field per capture group, unnamed groups are named by their index as in polars
+      let fields = compile_regex(pattern).map_err(PolarsError::from)?
+        .capture_names()
+        .enumerate()
+        .skip(1)
+        .map(|(i, name)| Field::new(name.map_or_else(|| i.to_string().into(), PlSmallStr::from_str), DataType::String))
+        .collect();
+      self.str_apply(|ca| ca.extract_groups(pattern, &DataType::Struct(fields)))
+    })
+  }
+
+  pub fn str_to_lowercase(&self) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_to_lowercase", || {
+      self.str_apply(|ca| Ok(ca.to_lowercase()))
+    })
+  }
+
+  pub fn str_to_uppercase(&self) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_to_uppercase", || {
+      self.str_apply(|ca| Ok(ca.to_uppercase()))
+    })
+  }
+
+  /// Strips any of the characters in `chars` (whitespace if `nothing`) from `side`.
+  /// side: :both, :start, :end
+  pub fn str_strip_chars(&self, chars: CCallRef<Value>, side: CCallRef<Symbol>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_strip_chars", || {
+      let chars = match chars.as_cast_opt::<JuliaString>()? {
+        Some(chars) => Column::new(PlSmallStr::EMPTY, [chars.as_str()?]),
+        None => Column::full_null(PlSmallStr::EMPTY, 1, &DataType::Null),
+      };
+      let side = side.as_managed()?.as_str()?;
+      if !matches!(side, "both" | "start" | "end") {
+        Err(PolarsJlError::OperationError(side.to_string()))?
+      }
+      self.str_apply(|ca| match side {
+        "start" => ca.strip_chars_start(&chars),
+        "end" => ca.strip_chars_end(&chars),
+        _ => ca.strip_chars(&chars),
+      })
+    })
+  }
+
+  pub fn str_len_bytes(&self) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_len_bytes", || {
+      self.str_apply(|ca| Ok(ca.str_len_bytes()))
+    })
+  }
+
+  pub fn str_len_chars(&self) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_len_chars", || {
+      self.str_apply(|ca| Ok(ca.str_len_chars()))
+    })
+  }
+
+  /// `length` characters from the 0-based `offset`, negative offsets count from the end.
+  /// A `nothing` length takes the rest of the string.
+  pub fn str_slice(&self, offset: i64, length: CCallRef<Value>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_slice", || {
+      let length = match length.as_value()?.is::<Nothing>() {
+        true => None,
+        false => Some(length.as_unbox::<u64>()?),
+      };
+      let (offset, length) = (Column::new(PlSmallStr::EMPTY, [offset]), Column::new(PlSmallStr::EMPTY, [length]));
+      self.str_apply(|ca| ca.str_slice(&offset, &length))
+    })
+  }
+
+  /// Pads with leading zeros to `length` characters, after a sign if there is one.
+  pub fn str_zfill(&self, length: usize) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_zfill", || {
+      let length = UInt64Chunked::from_slice(PlSmallStr::EMPTY, &[length as u64]);
+      self.str_apply(|ca| Ok(ca.zfill(&length)))
+    })
+  }
+
+  /// Parses into `dtype` (Date, Datetime or Time) with a chrono `format`, or infers
+  /// the format if it is `nothing`. A strict parse fails on values that don't match.
+  pub fn str_strptime(&self, dtype: ValueTypeRef, format: CCallRef<Value>, strict: bool) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_strptime", || {
+      let dtype = dtype.tracked_map(|dt| dt.inner.clone())?;
+      let format = match format.as_cast_opt::<JuliaString>()? {
+        Some(format) => Some(format.as_str()?),
+        None => None,
+      };
+      self.str_apply(|ca| strptime(ca, &dtype, format, strict))
+    })
+  }
+
+  /// Parses JSON values into `dtype`, or a dtype inferred from the values if it is `nothing`.
+  pub fn str_json_decode(&self, dtype: CCallRef<Value>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_str_json_decode", || {
+      let dtype = dtype.as_value()?;
+      let dtype = match dtype.is::<Nothing>() {
+        true => None,
+        false => Some(dtype.track_shared::<polars_value_type_t>()?.inner.clone()),
+      };
+      self.str_apply(|ca| ca.json_decode(dtype, None))
+    })
+  }
+
+  /// Runs `f` on the strings, the result keeps the column's name.
+  fn str_apply<C: IntoColumn>(&self, f: impl FnOnce(&StringChunked) -> PolarsResult<C>) -> PolarsJlResult<ColumnRet> {
+    let out = f(self.inner.str()?)?.into_column();
+    Ok(leak_value(Self { inner: out.with_name(self.inner.name().clone()) }))
+  }
+}
+
+/// Parses `ca` into `dtype` with the same defaults as the polars `str.strptime` expression,
+/// a strict parse fails if any non-null value doesn't match.
+fn strptime(ca: &StringChunked, dtype: &DataType, format: Option<&str>, strict: bool) -> PolarsResult<Series> {
+  let out = match dtype {
+    #[cfg(feature = "dtype-date")]
+    DataType::Date => ca.as_date(format, true)?.into_series(),
+    #[cfg(feature = "dtype-datetime")]
+    DataType::Datetime(tu, tz) => {
+      // an offset in the format makes the parsed values time zone aware
+      let tz_aware = format.is_some_and(|f| f == "%+" || ["%z", "%:z", "%::z", "%:::z", "%#z"].iter().any(|z| f.contains(z)));
+      let ambiguous = StringChunked::from_slice(PlSmallStr::EMPTY, &["raise"]);
+      ca.as_datetime(format, *tu, true, tz_aware, tz.as_ref(), &ambiguous)?.into_series()
+    },
+    #[cfg(feature = "dtype-time")]
+    DataType::Time => ca.as_time(format, true)?.into_series(),
+    dt => polars_bail!(InvalidOperation: "cannot parse strings into {}", dt),
+  };
+  if strict && out.null_count() != ca.null_count() {
+    polars_bail!(InvalidOperation: "{} values don't match the format", out.null_count() - ca.null_count());
+  }
+  Ok(out)
+}
@@ -0,0 +1,146 @@
+use polars::prelude::*;
+use jlrs::{data::managed::ccall_ref::CCallRef, prelude::*};
+
+use crate::{errors::{catch_panic, PolarsJlError, PolarsJlResult}, polars_column_t, utils::leak_value, value_types::time_unit_from_str, ColumnRet};
+
+/// Kernels of the polars `dt` namespace on Date, Datetime, Time and Duration columns.
+impl polars_column_t {
+  /// component: :millennium, :century, :year, :iso_year, :quarter, :month, :week, :weekday,
+  /// :day, :ordinal_day, :hour, :minute, :second, :millisecond, :microsecond, :nanosecond,
+  /// :is_leap_year, :date, :time
+  pub fn dt_component(&self, component: CCallRef<Symbol>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_dt_component", || {
+      let component = component.as_managed()?.as_str()?;
+      let f: fn(&Series) -> PolarsResult<Series> = match component {
+        "millennium" => |s| Ok(s.millennium()?.into_series()),
+        "century" => |s| Ok(s.century()?.into_series()),
+        "year" => |s| Ok(s.year()?.into_series()),
+        "iso_year" => |s| Ok(s.iso_year()?.into_series()),
+        "quarter" => |s| Ok(s.quarter()?.into_series()),
+        "month" => |s| Ok(s.month()?.into_series()),
+        "week" => |s| Ok(s.week()?.into_series()),
+        "weekday" => |s| Ok(s.weekday()?.into_series()),
+        "day" => |s| Ok(s.day()?.into_series()),
+        "ordinal_day" => |s| Ok(s.ordinal_day()?.into_series()),
+        "hour" => |s| Ok(s.hour()?.into_series()),
+        "minute" => |s| Ok(s.minute()?.into_series()),
+        "second" => |s| Ok(s.second()?.into_series()),
+        "millisecond" => |s| Ok(s.nanosecond()?.into_series().wrapping_trunc_div_scalar(1_000_000)),
+        "microsecond" => |s| Ok(s.nanosecond()?.into_series().wrapping_trunc_div_scalar(1_000)),
+        "nanosecond" => |s| Ok(s.nanosecond()?.into_series()),
+        "is_leap_year" => |s| Ok(s.is_leap_year()?.into_series()),
+        "date" => |s| match s.dtype() {
+          DataType::Date => Ok(s.clone()),
+          _ => wall_time(s)?.cast(&DataType::Date),
+        },
+        "time" => |s| match s.dtype() {
+          DataType::Time => Ok(s.clone()),
+          _ => wall_time(s)?.cast(&DataType::Time),
+        },
+        c => return Err(PolarsJlError::OperationError(c.to_string()))?,
+      };
+      self.dt_apply(f)
+    })
+  }
+
+  /// Rounds down to a multiple of `every`, an interval string like "1h" or "15m".
+  pub fn dt_truncate(&self, every: JuliaString) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_dt_truncate", || {
+      let every = StringChunked::from_slice(PlSmallStr::EMPTY, &[every.as_str()?]);
+      self.dt_apply(|s| match s.dtype() {
+        DataType::Datetime(_, tz) => {
+          let tz = tz.as_ref().map(|tz| tz.to_chrono()).transpose()?;
+          Ok(s.datetime()?.truncate(tz.as_ref(), &every)?.into_series())
+        },
+        DataType::Date => Ok(s.date()?.truncate(None, &every)?.into_series()),
+        dt => polars_bail!(opq = truncate, got = dt, expected = "date/datetime"),
+      })
+    })
+  }
+
+  /// Rounds to the nearest multiple of `every`, halfway values round up.
+  pub fn dt_round(&self, every: JuliaString) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_dt_round", || {
+      let every = StringChunked::from_slice(PlSmallStr::EMPTY, &[every.as_str()?]);
+      self.dt_apply(|s| match s.dtype() {
+        DataType::Datetime(_, tz) => {
+          let tz = tz.as_ref().map(|tz| tz.to_chrono()).transpose()?;
+          Ok(s.datetime()?.round(&every, tz.as_ref())?.into_series())
+        },
+        DataType::Date => Ok(s.date()?.round(&every, None)?.into_series()),
+        dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
+      })
+    })
+  }
+
+  /// Shifts by an interval string like "1mo" or "-2d", calendar aware.
+  pub fn dt_offset_by(&self, by: JuliaString) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_dt_offset_by", || {
+      let by = Series::new(PlSmallStr::EMPTY, [by.as_str()?]);
+      self.dt_apply(|s| impl_offset_by(s, &by))
+    })
+  }
+
+  /// Time since the unix epoch as integers of `unit`.
+  /// unit: :ns, :μs, :ms, :s, :d
+  pub fn dt_epoch(&self, unit: CCallRef<Symbol>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_dt_epoch", || {
+      match unit.as_managed()?.as_str()? {
+        "s" => self.dt_apply(|s| Ok(s.timestamp(TimeUnit::Milliseconds)?.apply_values(|ms| ms.div_euclid(1000)))),
+        "d" => self.dt_apply(|s| s.cast(&DataType::Date)?.cast(&DataType::Int32)),
+        unit => {
+          let tu = time_unit_from_str(unit)?;
+          self.dt_apply(|s| s.timestamp(tu))
+        },
+      }
+    })
+  }
+
+  /// Formats the values with chrono specifiers, e.g. "%Y-%m-%d %H:%M".
+  pub fn dt_strftime(&self, format: JuliaString) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_dt_strftime", || {
+      let format = format.as_str()?;
+      self.dt_apply(|s| s.strftime(format))
+    })
+  }
+
+  /// Length of a Duration as whole `unit`s.
+  /// unit: :days, :hours, :minutes, :seconds, :milliseconds, :microseconds, :nanoseconds
+  pub fn dt_total(&self, unit: CCallRef<Symbol>) -> JlrsResult<ColumnRet> {
+    catch_panic("polars_column_dt_total", || {
+      let unit = unit.as_managed()?.as_str()?;
+      let f: fn(&DurationChunked) -> Int64Chunked = match unit {
+        "days" => |ca| ca.days(),
+        "hours" => |ca| ca.hours(),
+        "minutes" => |ca| ca.minutes(),
+        "seconds" => |ca| ca.seconds(),
+        "milliseconds" => |ca| ca.milliseconds(),
+        "microseconds" => |ca| ca.microseconds(),
+        "nanoseconds" => |ca| ca.nanoseconds(),
+        u => return Err(PolarsJlError::OperationError(u.to_string()))?,
+      };
+      self.dt_apply(|s| Ok(f(s.duration()?)))
+    })
+  }
+
+  /// Runs `f` on a temporal column, the result keeps the column's name.
+  fn dt_apply<C: IntoColumn>(&self, f: impl FnOnce(&Series) -> PolarsResult<C>) -> PolarsJlResult<ColumnRet> {
+    if !self.inner.dtype().is_temporal() {
+      Err(polars_err!(InvalidOperation: "expected a temporal column, got {}", self.inner.dtype()))?
+    }
+    let out = f(self.inner.as_materialized_series())?.into_column();
+    Ok(leak_value(Self { inner: out.with_name(self.inner.name().clone()) }))
+  }
+}
+
+/// The naive wall time of a Datetime column, values with a time zone are shown in that zone.
+fn wall_time(s: &Series) -> PolarsResult<Series> {
+  let ca = s.datetime()?;
+  Ok(match ca.time_zone() {
+    Some(_) => {
+      let ambiguous = StringChunked::from_slice(PlSmallStr::EMPTY, &["raise"]);
+      replace_time_zone(ca, None, &ambiguous, NonExistent::Raise)?.into_series()
+    },
+    None => s.clone(),
+  })
+}
@@ -1,9 +1,9 @@
-use jlrs::{convert::{into_julia::IntoJulia, unbox::Unbox}, data::{layout::valid_layout::ValidLayout, managed::{ccall_ref::{CCallRef, CCallRefRet}, named_tuple::NamedTuple, string::StringRet, symbol::SymbolRet, value::typed::TypedValue, Weak}, types::{abstract_type::IO, construct_type::ConstructType, typecheck::Typecheck}}, inline_static_ref, prelude::*, weak_handle};
+use jlrs::{convert::{into_julia::IntoJulia, unbox::Unbox}, data::{layout::valid_layout::ValidLayout, managed::{ccall_ref::{CCallRef, CCallRefRet}, named_tuple::NamedTuple, string::StringRet, symbol::SymbolRet, value::{typed::TypedValue, ValueRet}, Weak}, types::{abstract_type::IO, construct_type::ConstructType, typecheck::Typecheck}}, inline_static_ref, prelude::*, weak_handle};
 
 use crate::errors::{PolarsJlError, PolarsJlResult};
 
-
-pub(crate) fn leak_symbol(s: &'static str) -> SymbolRet {
+// the leak_* helpers panic without a Julia context, every export calls them inside catch_panic
+pub(crate) fn leak_symbol<S: AsRef<str>>(s: S) -> SymbolRet {
   match weak_handle!() {
     Ok(handle) => {
       Symbol::new(&handle, s).leak()
@@ -164,3 +164,85 @@ impl<'scope, T: ConstructType + ValidLayout> CCallRefExt<'scope, T> for CCallRef
     Ok(f(&v))
   }
 }
+
+/// Copies `values` into a new Julia vector with element type `eltype`,
+/// entries whose `validity` flag is false become `nothing`.
+pub(crate) fn jl_vector<'scope, T: Copy, Tgt: Target<'scope>>(tgt: &Tgt, eltype: &'static str, values: &[T], validity: Option<&[bool]>) -> PolarsJlResult<ValueRet> {
+  tgt.local_scope::<_, 4>(|mut frame| {
+    // _jl_vector(eltype::Symbol, ptr::Ptr{UInt8}, valid::Ptr{UInt8}, n::UInt)
+    let _jl_vector = inline_static_ref!(JL_VECTOR_FUNCTION, Value, "Polars.FFI._jl_vector", frame);
+    let valid = match validity {
+      Some(validity) => validity.as_ptr() as *mut u8,
+      None => std::ptr::null_mut(),
+    };
+    let arg0 = Symbol::new(&frame, eltype).as_value();
+    let arg1 = (values.as_ptr() as *mut u8).into_julia(&mut frame);
+    let arg2 = valid.into_julia(&mut frame);
+    let arg3 = values.len().into_julia(&mut frame);
+    match unsafe { _jl_vector.call(&mut frame, [arg0, arg1, arg2, arg3]) } {
+      Ok(v) => Ok(v.leak()),
+      Err(e) => Err(PolarsJlError::function_call("Polars.FFI._jl_vector", e))?,
+    }
+  })
+}
+
+/// Reads the raw bits of a Julia vector back as values of type `T`.
+pub(crate) fn from_bits<T: Copy>(bytes: &[u8]) -> impl Iterator<Item = T> + '_ {
+  let ptr = bytes.as_ptr() as *const T;
+  (0..bytes.len() / std::mem::size_of::<T>()).map(move |i| unsafe { ptr.add(i).read_unaligned() })
+}
+
+/// Copies strings into a new Julia vector of `String`, `None` entries become `nothing`.
+pub(crate) fn jl_string_vector<'scope, 'a, Tgt: Target<'scope>>(tgt: &Tgt, values: impl Iterator<Item = Option<&'a str>>) -> PolarsJlResult<ValueRet> {
+  jl_bytes_vector(tgt, values.map(|v| v.map(str::as_bytes)), true)
+}
+
+/// Copies byte strings into a new Julia vector of `Vector{UInt8}`, `None` entries become `nothing`.
+pub(crate) fn jl_binary_vector<'scope, 'a, Tgt: Target<'scope>>(tgt: &Tgt, values: impl Iterator<Item = Option<&'a [u8]>>) -> PolarsJlResult<ValueRet> {
+  jl_bytes_vector(tgt, values, false)
+}
+
+fn jl_bytes_vector<'scope, 'a, Tgt: Target<'scope>>(tgt: &Tgt, values: impl Iterator<Item = Option<&'a [u8]>>, string: bool) -> PolarsJlResult<ValueRet> {
+  // the values are passed as one buffer plus offsets, like a list of UInt8
+  let mut data = Vec::<u8>::new();
+  let mut offsets = vec![0i64];
+  let mut validity = Vec::<bool>::new();
+  for v in values {
+    data.extend_from_slice(v.unwrap_or_default());
+    offsets.push(data.len() as i64);
+    validity.push(v.is_some());
+  }
+  let validity = validity.contains(&false).then_some(validity);
+  tgt.local_scope::<_, 4>(|mut frame| {
+    let data = unsafe { jl_vector(&frame, "UInt8", &data, None)?.root(&mut frame) };
+    let offsets = unsafe { jl_vector(&frame, "Int64", &offsets, None)?.root(&mut frame) };
+    let valid = match &validity {
+      Some(validity) => unsafe { jl_vector(&frame, "Bool", validity, None)?.root(&mut frame) },
+      None => Value::nothing(&frame),
+    };
+    let (func, result) = if string {
+      let _jl_string_vector = inline_static_ref!(JL_STRING_VECTOR_FUNCTION, Value, "Polars.FFI._jl_string_vector", frame);
+      ("Polars.FFI._jl_string_vector", unsafe { _jl_string_vector.call(&mut frame, [data, offsets, valid]) })
+    } else {
+      let _jl_list_vector = inline_static_ref!(JL_LIST_VECTOR_FUNCTION, Value, "Polars.FFI._jl_list_vector", frame);
+      ("Polars.FFI._jl_list_vector", unsafe { _jl_list_vector.call(&mut frame, [data, offsets, valid]) })
+    };
+    match result {
+      Ok(v) => Ok(v.leak()),
+      Err(e) => Err(PolarsJlError::function_call(func, e))?,
+    }
+  })
+}
+
+/// Copies the strings out of a Julia `Vector{String}`.
+pub(crate) fn extract_strings(v: TypedVector<JuliaString>) -> PolarsJlResult<Vec<String>> {
+  unsafe { v.managed_data() }
+    .as_slice()
+    .iter()
+    .enumerate()
+    .map(|(i, s)| match s.load(std::sync::atomic::Ordering::Relaxed) {
+      Some(s) => Ok(unsafe { s.as_managed() }.as_str()?.to_string()),
+      None => Err(PolarsJlError::ExtractBoxError(i)),
+    })
+    .collect()
+}
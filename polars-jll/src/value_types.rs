@@ -1,7 +1,11 @@
 use jlrs::{data::{managed::{ccall_ref::CCallRef, named_tuple::NamedTuple, string::StringRet, symbol::SymbolRet, value::{typed::TypedValue, ValueRet}}, types::construct_type::ConstructType}, inline_static_ref, prelude::*, weak_handle};
 use polars::prelude::TimeZone;
+#[cfg(feature = "dtype-categorical")]
+use polars::prelude::{CatSize, CategoricalPhysical, Categories, FrozenCategories};
 
-use crate::{errors::PolarsJlError, utils::{leak_string, leak_symbol, leak_value, JuliaNamedTupleExt, JuliaValueExt}};
+#[cfg(feature = "dtype-categorical")]
+use crate::utils::{extract_strings, jl_string_vector};
+use crate::{errors::{catch_panic, PolarsJlError, PolarsJlResult}, utils::{leak_string, leak_symbol, leak_value, JuliaNamedTupleExt, JuliaValueExt}};
 
 #[derive(Debug, OpaqueType)]
 #[allow(non_camel_case_types)]
@@ -16,16 +20,24 @@ pub type ValueTypeValue<'scope, 'data> = TypedValue<'scope, 'data, polars_value_
 
 
 impl polars_value_type_t {
-  pub fn display(&self) -> StringRet {
-    leak_string(format!("{}", self.inner))
+  pub fn display(&self) -> JlrsResult<StringRet> {
+    catch_panic("polars_value_type_display", || Ok(leak_string(format!("{}", self.inner))))
   }
 
-  pub fn symbol(&self) -> SymbolRet {
-    leak_symbol(as_str(&self.inner))
+  pub fn symbol(&self) -> JlrsResult<SymbolRet> {
+    catch_panic("polars_value_type_symbol", || Ok(leak_symbol(as_str(&self.inner))))
   }
 
   // this is actually JlrsResult<NamedTupleRet>
   pub fn kwargs(&self) -> JlrsResult<ValueRet> {
+    catch_panic("polars_value_type_kwargs", || self.kwargs_tuple())
+  }
+
+  pub fn from_name_and_kwargs<'scope, 'data>(name: CCallRef<'scope, Symbol<'scope>>, kwargs: CCallRef<'scope, NamedTuple<'scope, 'static>>) -> JlrsResult<ValueTypeRet> {
+    catch_panic("polars_value_type_from_name_and_kwargs", || Self::from_kwargs(name, kwargs))
+  }
+
+  fn kwargs_tuple(&self) -> PolarsJlResult<ValueRet> {
     use jlrs::convert::into_julia::IntoJulia;
     match weak_handle!() {
       Ok(handle) => {
@@ -100,6 +112,34 @@ impl polars_value_type_t {
               vals.push(jl_none());
             }
           }
+          #[cfg(feature = "dtype-struct")]
+          polars::prelude::DataType::Struct(fields) => {
+            let names = fields.iter().map(|f| sym(f.name().as_str())).collect::<Vec<_>>();
+            let dtypes = fields.iter().map(|f| jl_dtype(f.dtype())).collect::<Vec<_>>();
+            let fields = NamedTuple::new(&handle, &names, &dtypes)
+              .map_err(|e| PolarsJlError::function_call("NamedTuple::new", e))?;
+            keys.push(sym("fields"));
+            vals.push(unsafe { fields.as_value() });
+          },
+          #[cfg(feature = "dtype-categorical")]
+          polars::prelude::DataType::Categorical(cats, mapping) => {
+            keys.push(sym("name"));
+            vals.push(jl_str(cats.name()));
+            keys.push(sym("namespace"));
+            vals.push(jl_str(cats.namespace()));
+            keys.push(sym("physical"));
+            vals.push(jl_sym(cats.physical().as_str()));
+            // every category of the mapping, which is shared by all columns of the namespace,
+            // `Polars.dtype(col)` swaps in the column's own categories
+            let categories = (0..mapping.num_cats_upper_bound()).filter_map(|i| mapping.cat_to_str(i as CatSize));
+            keys.push(sym("categories"));
+            vals.push(unsafe { jl_string_vector(&handle, categories.map(Some))?.as_value() });
+          },
+          #[cfg(feature = "dtype-categorical")]
+          polars::prelude::DataType::Enum(fcats, _) => {
+            keys.push(sym("categories"));
+            vals.push(unsafe { jl_string_vector(&handle, fcats.categories().values_iter().map(Some))?.as_value() });
+          },
           raw => {
             keys.push(sym("raw"));
             vals.push(jl_dtype(raw));
@@ -109,24 +149,18 @@ impl polars_value_type_t {
           .map_err(|e| PolarsJlError::function_call("NamedTuple::new", e))?;
         Ok(unsafe { result.as_value().leak() })
       },
-      Err(_) => PolarsJlError::WeakHandleError("polars_value_type_t::kwargs").panic(),
+      Err(_) => Err(PolarsJlError::WeakHandleError("polars_value_type_t::kwargs"))?,
     }
   }
 
-  pub fn from_name_and_kwargs<'scope, 'data>(name: CCallRef<'scope, Symbol<'scope>>, kwargs: CCallRef<'scope, NamedTuple<'scope, 'static>>) -> JlrsResult<ValueTypeRet> {
+  fn from_kwargs<'scope>(name: CCallRef<'scope, Symbol<'scope>>, kwargs: CCallRef<'scope, NamedTuple<'scope, 'static>>) -> PolarsJlResult<ValueTypeRet> {
     match weak_handle!() {
       Ok(handle) => {
         let name = name.as_managed()?.as_str()?;
         let kwargs = kwargs.as_managed()?;
-        println!("from_name_and_kwargs: name={}, kwargs={:?}", name, kwargs.field_names().iter().map(|i| i.as_str().unwrap_or_default()).collect::<Vec<_>>());
         let get_tu = || -> JlrsResult<_> {
           let s = kwargs.get_value(&handle, "time_unit")?.cast::<Symbol>()?;
-          match s.as_str()? {
-            "ns" => Ok(polars::prelude::TimeUnit::Nanoseconds),
-            "μs" => Ok(polars::prelude::TimeUnit::Microseconds),
-            "ms" => Ok(polars::prelude::TimeUnit::Milliseconds),
-            s => Err(PolarsJlError::TimeUnitError(s.to_string()))?,
-          }
+          Ok(time_unit_from_str(s.as_str()?)?)
         };
         let get_tz = || -> JlrsResult<_> {
           let Ok(v) = kwargs.get_value(&handle, "time_zone") else {
@@ -137,8 +171,7 @@ impl polars_value_type_t {
           };
           Ok(Some(v.as_str()?.to_string()))
         };
-        let get_dtype = |key: &str| -> JlrsResult<_> {
-          let v = kwargs.get_value(&handle, key)?;
+        let as_dtype = |v: Value| -> JlrsResult<_> {
           let intoraw = inline_static_ref!(INTORAW_FUNCTION, Value, "Polars.DataTypes.intoraw", handle);
           match v.track_shared::<polars_value_type_t>() {
             Ok(dt) => Ok(dt.inner.clone()),
@@ -151,9 +184,11 @@ impl polars_value_type_t {
             },
           }
         };
+        let get_dtype = |key: &str| -> JlrsResult<_> {
+          as_dtype(kwargs.get_value(&handle, key)?)
+        };
         let get_size = |key: &str| -> JlrsResult<_> {
           let size = kwargs.get_value(&handle, key)?.unbox::<i64>()?;
-          // println!("size value: k={} {:?}", key, size);
           Ok(size as usize)
         };
         let dtype = match name {
@@ -208,11 +243,43 @@ impl polars_value_type_t {
             };
             polars::prelude::DataType::Decimal(precision, scale)
           },
+          #[cfg(feature = "dtype-struct")]
+          "Struct" => {
+            // fields is a NamedTuple of field name => dtype
+            let fields = kwargs.get_value(&handle, "fields")?.cast::<NamedTuple>()?;
+            let fields = fields.field_names().iter().map(|name| -> JlrsResult<_> {
+              let name = name.as_str()?;
+              let dtype = as_dtype(fields.get_value(&handle, name)?)?;
+              Ok(polars::prelude::Field::new(name.into(), dtype))
+            }).collect::<JlrsResult<Vec<_>>>()?;
+            polars::prelude::DataType::Struct(fields)
+          },
+          #[cfg(feature = "dtype-categorical")]
+          "Categorical" => {
+            let get_str = |key: &str| -> JlrsResult<_> {
+              Ok(kwargs.get_value(&handle, key)?.cast::<JuliaString>()?.as_str()?.to_string())
+            };
+            let physical = kwargs.get_value(&handle, "physical")?.cast::<Symbol>()?;
+            let physical = physical.as_str()?;
+            let physical = physical.parse::<CategoricalPhysical>()
+              .map_err(|_| PolarsJlError::CategoricalPhysicalError(physical.to_string()))?;
+            let cats = Categories::new(get_str("name")?.into(), get_str("namespace")?.into(), physical);
+            polars::prelude::DataType::from_categories(cats)
+          },
+          #[cfg(feature = "dtype-categorical")]
+          "Enum" => {
+            // the nth category gets id n, so the order of the vector is the enum order
+            let categories = kwargs.get_value(&handle, "categories")?.cast::<TypedVector<JuliaString>>()?;
+            let categories = extract_strings(categories)?;
+            let fcats = FrozenCategories::new(categories.iter().map(|s| s.as_str()))
+              .map_err(PolarsJlError::from)?;
+            polars::prelude::DataType::from_frozen_categories(fcats)
+          },
           s => return Err(PolarsJlError::UnsupportedDataType(s.to_string()))?,
         };
         Ok(leak_value(polars_value_type_t { inner: dtype }))
       },
-      Err(_) => PolarsJlError::WeakHandleError("polars_value_type_t::from_name_and_kwargs").panic(),
+      Err(_) => Err(PolarsJlError::WeakHandleError("polars_value_type_t::from_name_and_kwargs"))?,
     }
   }
 }
@@ -261,3 +328,18 @@ pub fn time_unit_as_str(tu: &polars::prelude::TimeUnit) -> &'static str {
     polars::prelude::TimeUnit::Milliseconds => "ms",
   }
 }
+
+pub fn time_unit_from_str(s: &str) -> PolarsJlResult<polars::prelude::TimeUnit> {
+  match s {
+    "ns" => Ok(polars::prelude::TimeUnit::Nanoseconds),
+    "μs" => Ok(polars::prelude::TimeUnit::Microseconds),
+    "ms" => Ok(polars::prelude::TimeUnit::Milliseconds),
+    s => Err(PolarsJlError::TimeUnitError(s.to_string())),
+  }
+}
+
+/// Parses a polars interval string like "1h30m" or "3i". Microseconds may be written
+/// "μs" as in `time_unit_as_str`.
+pub fn duration_from_str(s: &str) -> PolarsJlResult<polars::prelude::Duration> {
+  Ok(polars::prelude::Duration::try_parse(&s.replace("μs", "us"))?)
+}
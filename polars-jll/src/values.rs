@@ -1,7 +1,7 @@
 use polars::prelude::*;
-use jlrs::{data::{managed::{value::{typed::TypedValue, ValueRet}}, types::construct_type::ConstructType}, inline_static_ref, prelude::*, weak_handle};
+use jlrs::{convert::into_julia::IntoJulia, data::{managed::{symbol::SymbolRet, value::{typed::TypedValue, ValueRet}}, types::construct_type::ConstructType}, inline_static_ref, prelude::*, weak_handle};
 
-use crate::{errors::{PolarsJlError, PolarsJlResult}, polars_value_type_t, utils::leak_value, value_types::time_unit_as_str, ValueTypeRet};
+use crate::{columns::column_to_vector, errors::{catch_panic, PolarsJlError, PolarsJlResult}, polars_value_type_t, utils::{extract_strings, jl_vector, leak_symbol, leak_value, CCallRefExt, TypedVecExt}, value_types::time_unit_as_str, ValueTypeRef, ValueTypeRet};
 
 #[derive(Debug, OpaqueType)]
 #[allow(non_camel_case_types)]
@@ -14,59 +14,185 @@ pub type AnyValueRef<'scope> = jlrs::data::managed::ccall_ref::CCallRef<'scope,
 pub type AnyValueValue<'scope, 'data> = TypedValue<'scope, 'data, polars_value_t>;
 
 impl polars_value_t {
-  pub fn dtype(&self) -> ValueTypeRet {
-    leak_value(polars_value_type_t { inner: self.inner.dtype() })
+  pub fn new_null() -> JlrsResult<AnyValueRet> {
+    catch_panic("polars_value_new_null", || Ok(leak_value(Self { inner: AnyValue::Null })))
   }
 
-  pub fn extract(&self) -> JlrsResult<ValueRet> {
-    use jlrs::convert::into_julia::IntoJulia;
+  /// A scalar of `dtype` from its physical bits as `polars_column_t::from_bits` takes them,
+  /// `hi` and `lo` are the upper and lower halves of the bits as an Int128.
+  pub fn from_bits(dtype: ValueTypeRef, hi: i64, lo: u64) -> JlrsResult<AnyValueRet> {
+    catch_panic("polars_value_from_bits", || {
+      let dtype = dtype.tracked_map(|dt| dt.inner.clone())?;
+      let wide = ((hi as i128) << 64) | lo as i128;
+      let value = match dtype {
+        DataType::Boolean => AnyValue::Boolean(lo != 0),
+        #[cfg(feature = "dtype-i8")]
+        DataType::Int8 => AnyValue::Int8(lo as i8),
+        #[cfg(feature = "dtype-i16")]
+        DataType::Int16 => AnyValue::Int16(lo as i16),
+        DataType::Int32 => AnyValue::Int32(lo as i32),
+        DataType::Int64 => AnyValue::Int64(lo as i64),
+        #[cfg(feature = "dtype-i128")]
+        DataType::Int128 => AnyValue::Int128(wide),
+        #[cfg(feature = "dtype-u8")]
+        DataType::UInt8 => AnyValue::UInt8(lo as u8),
+        #[cfg(feature = "dtype-u16")]
+        DataType::UInt16 => AnyValue::UInt16(lo as u16),
+        DataType::UInt32 => AnyValue::UInt32(lo as u32),
+        DataType::UInt64 => AnyValue::UInt64(lo),
+        DataType::Float32 => AnyValue::Float32(f32::from_bits(lo as u32)),
+        DataType::Float64 => AnyValue::Float64(f64::from_bits(lo)),
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => AnyValue::Date(lo as i32),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, tz) => AnyValue::DatetimeOwned(lo as i64, tu, tz.map(std::sync::Arc::new)),
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(tu) => AnyValue::Duration(lo as i64, tu),
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => AnyValue::Time(lo as i64),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, scale) => AnyValue::Decimal(wide, scale.unwrap_or(0)),
+        dt => return Err(PolarsJlError::UnsupportedDataType(dt.to_string()))?,
+      };
+      Ok(leak_value(Self { inner: value }))
+    })
+  }
 
-    match weak_handle!() {
-      Ok(handle) => {
-        fn jl_value<'s, 'd, T: IntoJulia + ConstructType>(handle: impl Target<'s>, v: T) -> ValueRet {
-          Value::new(&handle, v).leak()
-        }
-        let jl_str = |s: &str| unsafe { JuliaString::new(&handle, s).as_value() }.leak();
-        match &self.inner {
-          AnyValue::Null => Ok(Value::nothing(&handle).leak()),
-          AnyValue::Boolean(v) => Ok(jl_value(&handle, *v)),
-          AnyValue::String(v) => Ok(jl_str(v)),
-          AnyValue::StringOwned(v) => Ok(jl_str(v)),
-          AnyValue::UInt8(v) => Ok(jl_value(&handle, *v)),
-          AnyValue::UInt16(v) => Ok(jl_value(&handle, *v)),
-          AnyValue::UInt32(v) => Ok(jl_value(&handle, *v)),
-          AnyValue::UInt64(v) => Ok(jl_value(&handle, *v)),
-          AnyValue::Int8(v) => Ok(jl_value(&handle, *v)),
-          AnyValue::Int16(v) => Ok(jl_value(&handle, *v)),
-          AnyValue::Int32(v) => Ok(jl_value(&handle, *v)),
-          AnyValue::Int64(v) => Ok(jl_value(&handle, *v)),
-          AnyValue::Float32(v) => Ok(jl_value(&handle, *v)),
-          AnyValue::Float64(v) => Ok(jl_value(&handle, *v)),
-          #[cfg(feature = "dtype-date")]
-          AnyValue::Date(v) => Ok(jl_date(&handle, *v)?),
-          #[cfg(feature = "dtype-datetime")]
-          AnyValue::Datetime(v, unit, tz) => {
-            let tz = tz.map(|s| s.as_str());
-            let unit = time_unit_as_str(unit);
-            Ok(jl_datetime(&handle, *v, unit, tz)?)
-          },
-          #[cfg(feature = "dtype-datetime")]
-          AnyValue::DatetimeOwned(v, unit, tz) => {
-            let tz = tz.as_ref().map(|s| s.as_str());
-            let unit = time_unit_as_str(unit);
-            Ok(jl_datetime(&handle, *v, unit, tz)?)
-          },
-          #[cfg(feature = "dtype-time")]
-          AnyValue::Time(v) => Ok(jl_time(&handle, *v)?),
-          #[cfg(feature = "dtype-duration")]
-          AnyValue::Duration(v, unit) => Ok(jl_period(&handle, *v, time_unit_as_str(unit))?),
-          _ => {
-            let tag = as_tag_str(&self.inner);
-            Err(PolarsJlError::UnsupportedAnyValue(tag))?
-          },
+  pub fn new_string(value: JuliaString) -> JlrsResult<AnyValueRet> {
+    catch_panic("polars_value_new_string", || {
+      Ok(leak_value(Self { inner: AnyValue::StringOwned(value.as_str()?.into()) }))
+    })
+  }
+
+  pub fn new_binary(value: TypedVector<u8>) -> JlrsResult<AnyValueRet> {
+    catch_panic("polars_value_new_binary", || {
+      let value = unsafe { value.bits_data() };
+      Ok(leak_value(Self { inner: AnyValue::BinaryOwned(value.as_slice().to_vec()) }))
+    })
+  }
+
+  /// A List value holding `values`, which must share a dtype.
+  pub fn new_list(values: TypedVector<AnyValueValue>) -> JlrsResult<AnyValueRet> {
+    catch_panic("polars_value_new_list", || {
+      let values = values.extract_box(|v| v.inner.clone())?;
+      let s = Series::from_any_values(PlSmallStr::EMPTY, &values, true).map_err(PolarsJlError::from)?;
+      Ok(leak_value(Self { inner: AnyValue::List(s) }))
+    })
+  }
+
+  /// A Struct value with one field per name, the dtypes follow the values.
+  pub fn new_struct(names: TypedVector<JuliaString>, values: TypedVector<AnyValueValue>) -> JlrsResult<AnyValueRet> {
+    catch_panic("polars_value_new_struct", || {
+      let names = extract_strings(names)?;
+      let values = values.extract_box(|v| v.inner.clone())?;
+      #[cfg(feature = "dtype-struct")]
+      {
+        let fields = names.into_iter().zip(&values).map(|(name, v)| Field::new(name.into(), v.dtype())).collect();
+        Ok(leak_value(Self { inner: AnyValue::StructOwned(Box::new((values, fields))) }))
+      }
+      #[cfg(not(feature = "dtype-struct"))]
+      Err(PolarsJlError::UnsupportedDataType("Struct".to_string()))?
+    })
+  }
+
+  pub fn dtype(&self) -> JlrsResult<ValueTypeRet> {
+    catch_panic("polars_value_dtype", || Ok(leak_value(polars_value_type_t { inner: self.inner.dtype() })))
+  }
+
+  pub fn extract(&self) -> JlrsResult<ValueRet> {
+    catch_panic("polars_value_extract", || {
+      match weak_handle!() {
+        Ok(handle) => {
+          fn jl_value<'s, 'd, T: IntoJulia + ConstructType>(handle: impl Target<'s>, v: T) -> ValueRet {
+            Value::new(&handle, v).leak()
+          }
+          let jl_str = |s: &str| unsafe { JuliaString::new(&handle, s).as_value() }.leak();
+          match &self.inner {
+            AnyValue::Null => Ok(Value::nothing(&handle).leak()),
+            AnyValue::Boolean(v) => Ok(jl_value(&handle, *v)),
+            AnyValue::String(v) => Ok(jl_str(v)),
+            AnyValue::StringOwned(v) => Ok(jl_str(v)),
+            AnyValue::Binary(v) => Ok(jl_vector(&handle, "UInt8", &v[..], None)?),
+            AnyValue::BinaryOwned(v) => Ok(jl_vector(&handle, "UInt8", &v[..], None)?),
+            AnyValue::UInt8(v) => Ok(jl_value(&handle, *v)),
+            AnyValue::UInt16(v) => Ok(jl_value(&handle, *v)),
+            AnyValue::UInt32(v) => Ok(jl_value(&handle, *v)),
+            AnyValue::UInt64(v) => Ok(jl_value(&handle, *v)),
+            AnyValue::Int8(v) => Ok(jl_value(&handle, *v)),
+            AnyValue::Int16(v) => Ok(jl_value(&handle, *v)),
+            AnyValue::Int32(v) => Ok(jl_value(&handle, *v)),
+            AnyValue::Int64(v) => Ok(jl_value(&handle, *v)),
+            AnyValue::Int128(v) => Ok(jl_int128(&handle, *v)?),
+            AnyValue::Float32(v) => Ok(jl_value(&handle, *v)),
+            AnyValue::Float64(v) => Ok(jl_value(&handle, *v)),
+            #[cfg(feature = "dtype-date")]
+            AnyValue::Date(v) => Ok(jl_date(&handle, *v)?),
+            #[cfg(feature = "dtype-datetime")]
+            AnyValue::Datetime(v, unit, tz) => {
+              let tz = tz.map(|s| s.as_str());
+              let unit = time_unit_as_str(unit);
+              Ok(jl_datetime(&handle, *v, unit, tz)?)
+            },
+            #[cfg(feature = "dtype-datetime")]
+            AnyValue::DatetimeOwned(v, unit, tz) => {
+              let tz = tz.as_ref().map(|s| s.as_str());
+              let unit = time_unit_as_str(unit);
+              Ok(jl_datetime(&handle, *v, unit, tz)?)
+            },
+            #[cfg(feature = "dtype-time")]
+            AnyValue::Time(v) => Ok(jl_time(&handle, *v)?),
+            #[cfg(feature = "dtype-duration")]
+            AnyValue::Duration(v, unit) => Ok(jl_period(&handle, *v, time_unit_as_str(unit))?),
+            #[cfg(feature = "dtype-decimal")]
+            AnyValue::Decimal(v, scale) => Ok(jl_decimal(&handle, *v, *scale)?),
+            AnyValue::List(s) => Ok(column_to_vector(&handle, &s.clone().into_column())?),
+            #[cfg(feature = "dtype-array")]
+            AnyValue::Array(s, _) => Ok(column_to_vector(&handle, &s.clone().into_column())?),
+            #[cfg(feature = "dtype-struct")]
+            AnyValue::StructOwned(_) => Ok(jl_struct(&handle, self)?),
+            #[cfg(feature = "dtype-categorical")]
+            AnyValue::Categorical(cat, mapping) | AnyValue::Enum(cat, mapping) => Ok(jl_str(category(*cat, mapping)?)),
+            #[cfg(feature = "dtype-categorical")]
+            AnyValue::CategoricalOwned(cat, mapping) | AnyValue::EnumOwned(cat, mapping) => Ok(jl_str(category(*cat, mapping)?)),
+            _ => {
+              let tag = as_tag_str(&self.inner);
+              Err(PolarsJlError::UnsupportedAnyValue(tag))?
+            },
+          }
         }
+        Err(_) => Err(PolarsJlError::WeakHandleError("polars_value_t::extract"))?,
       }
-      Err(_) => PolarsJlError::WeakHandleError("polars_value_t::extract").panic(),
+    })
+  }
+
+  pub fn struct_len(&self) -> JlrsResult<usize> {
+    catch_panic("polars_value_struct_len", || {
+      let (values, _) = self.struct_payload()?;
+      Ok(values.len())
+    })
+  }
+
+  pub fn struct_field_name(&self, idx: usize) -> JlrsResult<SymbolRet> {
+    catch_panic("polars_value_struct_field_name", || {
+      let (_, fields) = self.struct_payload()?;
+      let field = fields.get(idx).ok_or_else(|| polars_err!(oob = idx, fields.len()))?;
+      Ok(leak_symbol(field.name().as_str()))
+    })
+  }
+
+  pub fn struct_field(&self, idx: usize) -> JlrsResult<AnyValueRet> {
+    catch_panic("polars_value_struct_field", || {
+      let (values, _) = self.struct_payload()?;
+      let v = values.get(idx).ok_or_else(|| polars_err!(oob = idx, values.len()))?;
+      Ok(leak_value(polars_value_t { inner: v.clone() }))
+    })
+  }
+
+  fn struct_payload(&self) -> PolarsJlResult<(&[AnyValue<'static>], &[Field])> {
+    match &self.inner {
+      #[cfg(feature = "dtype-struct")]
+      AnyValue::StructOwned(payload) => Ok((&payload.0, &payload.1)),
+      v => Err(PolarsJlError::UnsupportedAnyValue(as_tag_str(v))),
     }
   }
 }
@@ -135,6 +261,23 @@ fn jl_datetime<'scope>(handle: &impl Target<'scope>, s: i64, unit: &'static str,
   }
 }
 
+#[cfg(feature = "dtype-datetime")]
+pub(crate) fn jl_datetime_vector<'scope>(handle: &impl Target<'scope>, values: &[i64], validity: Option<&[bool]>, unit: &'static str, tz: Option<&str>) -> PolarsJlResult<ValueRet> {
+  handle.local_scope::<_, 4>(|mut frame| {
+    let values = unsafe { jl_vector(&frame, "Int64", values, validity)?.root(&mut frame) };
+    let unit = Symbol::new(&frame, unit).as_value();
+    let tz = match tz {
+      Some(tz) => JuliaString::new(&mut frame, tz).as_value(),
+      None => Value::nothing(&frame),
+    };
+    let _jl_datetime_vector = inline_static_ref!(JL_DATETIME_VECTOR_FUNCTION, Value, "Polars.FFI._jl_datetime_vector", frame);
+    match unsafe { _jl_datetime_vector.call(&mut frame, [values, unit, tz]) } {
+      Ok(v) => Ok(v.leak()),
+      Err(e) => Err(PolarsJlError::function_call("_jl_datetime_vector", e))?,
+    }
+  })
+}
+
 fn jl_date<'scope>(handle: &impl Target<'scope>, d: i32) -> PolarsJlResult<ValueRet> {
   let d = unsafe { TypedValue::new(handle, d).as_value() };
   let _jl_date = inline_static_ref!(JL_DATE_FUNCTION, Value, "Polars.FFI._jl_date", handle);
@@ -162,3 +305,61 @@ fn jl_period<'scope>(handle: &impl Target<'scope>, d: i64, unit: &'static str) -
     Err(e) => Err(PolarsJlError::function_call("_jl_period", e))?,
   }
 }
+
+fn jl_int128<'scope>(handle: &impl Target<'scope>, v: i128) -> PolarsJlResult<ValueRet> {
+  handle.local_scope::<_, 3>(|mut frame| {
+    // Int128 is passed as two halves
+    let hi = ((v >> 64) as i64).into_julia(&mut frame);
+    let lo = (v as u64).into_julia(&mut frame);
+    let _jl_int128 = inline_static_ref!(JL_INT128_FUNCTION, Value, "Polars.FFI._jl_int128", frame);
+    match unsafe { _jl_int128.call(&mut frame, [hi, lo]) } {
+      Ok(v) => Ok(v.leak()),
+      Err(e) => Err(PolarsJlError::function_call("_jl_int128", e))?,
+    }
+  })
+}
+
+#[cfg(feature = "dtype-decimal")]
+fn jl_decimal<'scope>(handle: &impl Target<'scope>, v: i128, scale: usize) -> PolarsJlResult<ValueRet> {
+  handle.local_scope::<_, 4>(|mut frame| {
+    // Int128 is passed as two halves
+    let hi = ((v >> 64) as i64).into_julia(&mut frame);
+    let lo = (v as u64).into_julia(&mut frame);
+    let scale = scale.into_julia(&mut frame);
+    let _jl_decimal = inline_static_ref!(JL_DECIMAL_FUNCTION, Value, "Polars.FFI._jl_decimal", frame);
+    match unsafe { _jl_decimal.call(&mut frame, [hi, lo, scale]) } {
+      Ok(v) => Ok(v.leak()),
+      Err(e) => Err(PolarsJlError::function_call("_jl_decimal", e))?,
+    }
+  })
+}
+
+#[cfg(feature = "dtype-decimal")]
+pub(crate) fn jl_decimal_vector<'scope>(handle: &impl Target<'scope>, values: &[i128], validity: Option<&[bool]>, scale: usize) -> PolarsJlResult<ValueRet> {
+  handle.local_scope::<_, 3>(|mut frame| {
+    let values = unsafe { jl_vector(&frame, "Int128", values, validity)?.root(&mut frame) };
+    let scale = scale.into_julia(&mut frame);
+    let _jl_decimal_vector = inline_static_ref!(JL_DECIMAL_VECTOR_FUNCTION, Value, "Polars.FFI._jl_decimal_vector", frame);
+    match unsafe { _jl_decimal_vector.call(&mut frame, [values, scale]) } {
+      Ok(v) => Ok(v.leak()),
+      Err(e) => Err(PolarsJlError::function_call("_jl_decimal_vector", e))?,
+    }
+  })
+}
+
+#[cfg(feature = "dtype-categorical")]
+fn category(cat: CatSize, mapping: &CategoricalMapping) -> PolarsJlResult<&str> {
+  Ok(mapping.cat_to_str(cat).ok_or_else(|| polars_err!(ComputeError: "invalid category id: {}", cat))?)
+}
+
+#[cfg(feature = "dtype-struct")]
+fn jl_struct<'scope>(handle: &impl Target<'scope>, v: &polars_value_t) -> PolarsJlResult<ValueRet> {
+  handle.local_scope::<_, 2>(|mut frame| {
+    let v = Value::new(&mut frame, polars_value_t { inner: v.inner.clone() });
+    let _jl_struct = inline_static_ref!(JL_STRUCT_FUNCTION, Value, "Polars.FFI._jl_struct", frame);
+    match unsafe { _jl_struct.call(&mut frame, [v]) } {
+      Ok(v) => Ok(v.leak()),
+      Err(e) => Err(PolarsJlError::function_call("_jl_struct", e))?,
+    }
+  })
+}
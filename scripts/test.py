@@ -21,12 +21,17 @@ df = pl.DataFrame({
   "col_decimal": pl.Series([1.0, 2.0, 3.0], dtype=pl.Decimal(9, 3)),
   "col_string": pl.Series(["a", "b", "c"], dtype=pl.String),
   "col_datetime": pl.Series([datetime(2023, 1, 1), datetime(2023, 1, 2), datetime(2023, 1, 3)], dtype=pl.Datetime),
+  "col_datetime_ns": pl.Series([1, 2, 3], dtype=pl.Int64).cast(pl.Datetime("ns")),
+  "col_datetime_tz": pl.Series([datetime(2023, 1, 1), datetime(2023, 1, 2), datetime(2023, 1, 3)], dtype=pl.Datetime("ms")).dt.replace_time_zone("Asia/Shanghai"),
   "col_date": pl.Series([date(2023, 1, 1), date(2023, 1, 2), date(2023, 1, 3)], dtype=pl.Date),
   "col_time": pl.Series([time(12, 0, 0), time(13, 0, 0), time(14, 0, 0)], dtype=pl.Time),
   "col_duration": pl.Series([1000, 2000, 3000], dtype=pl.Duration),
   "col_binary": pl.Series([b"a", b"b", b"c"], dtype=pl.Binary),
   "col_list_int32": pl.Series([[1, 2], [3, 4], [5]], dtype=pl.List(pl.Int32)),
   "col_array_float64": pl.Series([[1.0], [3.0], [5.0]], dtype=pl.Array(pl.Float64, 1)),
+  "col_struct": pl.Series([{"a": 1, "b": 1.5}, {"a": 2, "b": None}, None], dtype=pl.Struct({"a": pl.Int64, "b": pl.Float64})),
+  "col_categorical": pl.Series(["x", None, "x"], dtype=pl.Categorical),
+  "col_enum": pl.Series(["lo", "hi", None], dtype=pl.Enum(["lo", "mid", "hi"])),
 })
 df.write_parquet(workspace_dir / "test.parquet")
 
@@ -46,11 +51,16 @@ assert isinstance(df['col_float64'].dtype, pl.Float64)
 assert isinstance(df['col_string'].dtype, pl.String)
 assert isinstance(df['col_decimal'].dtype, pl.Decimal)
 assert isinstance(df['col_datetime'].dtype, pl.Datetime)
+assert df['col_datetime_ns'].dtype == pl.Datetime("ns")
+assert df['col_datetime_tz'].dtype == pl.Datetime("ms", "Asia/Shanghai")
 assert isinstance(df['col_date'].dtype, pl.Date)
 assert isinstance(df['col_time'].dtype, pl.Time)
 assert isinstance(df['col_duration'].dtype, pl.Duration)
 assert isinstance(df['col_binary'].dtype, pl.Binary)
 assert isinstance(df['col_list_int32'].dtype, pl.List)
 assert isinstance(df['col_array_float64'].dtype, pl.Array)
+assert isinstance(df['col_struct'].dtype, pl.Struct)
+assert isinstance(df['col_categorical'].dtype, pl.Categorical)
+assert isinstance(df['col_enum'].dtype, pl.Enum)
 
 # %%
@@ -1,11 +1,20 @@
 module Polars
 
+import Dates
+import Statistics
+
+include("decimal.jl")
+include("timestamp.jl")
 include("ffi.jl")
+include("errors.jl")
 include("datatype.jl")
 
 version()::String = FFI.polars_version()
 
 import .DataTypes: DataType
+import .Decimals: Decimal
+import .Errors: PolarsError
+import .Timestamps: Timestamp
 import .FFI: polars_error_t, polars_value_type_t
 
 struct DataFrame
@@ -16,6 +25,11 @@ struct Column
   inner::FFI.polars_column_t
 end
 
+# a query over scanned files or frames, nothing is read until it is collected or sunk
+struct LazyFrame
+  inner::FFI.polars_lazyframe_t
+end
+
 DataFrame()::DataFrame = FFI.polars_dataframe_new_empty()
 DataFrame(cols::Vector{Column})::DataFrame = FFI.polars_dataframe_from_cols([col.inner for col in cols])
 Base.convert(::Type{DataFrame}, df::FFI.polars_dataframe_t) = DataFrame(df)
@@ -27,20 +41,300 @@ Base.getindex(df::DataFrame, names::AbstractArray{String})::Vector{Column} = FFI
 Base.getindex(df::DataFrame, name::String, range) = Base.getindex(df[name], range)
 Base.getindex(df::DataFrame, names::AbstractArray{String}, range) = [Base.getindex.(Ref(df[name]), range) for name in names]
 height(df::DataFrame)::UInt = FFI.polars_dataframe_height(df.inner)
-read_parquet(path::String)::DataFrame = FFI.polars_dataframe_read_parquet(path)
-write_parquet(df::DataFrame, path::String)::Nothing = FFI.polars_dataframe_write_parquet(df.inner, path)
+"""
+    read_files(paths; format, how=:vertical, include_file_paths=nothing)
+
+Reads the files matched by `paths`, a file name, directory or glob like "data/*.csv", or a vector of those,
+in parallel and concatenates them in order. `format` is `:parquet`, `:csv` or `:ipc`.
+`how` reconciles differing schemas as in `concat`: `:vertical_relaxed` casts columns to a common type and
+`:diagonal` fills columns missing from some files with nulls.
+`include_file_paths` names a column holding the path of the file each row was read from.
+A parquet or ipc directory or glob is read as one dataset: its files share a schema and hive
+partitions in the directory names (e.g. `year=2024/`) become columns.
+"""
+function read_files(paths; format::Symbol, how::Symbol=:vertical, include_file_paths::Union{AbstractString, Nothing}=nothing)::DataFrame
+  include_file_paths = include_file_paths === nothing ? nothing : String(include_file_paths)
+  return FFI.polars_dataframe_read_files(_names(paths), format, how, include_file_paths)
+end
+# a single file name without options is read directly, globs, directories and options as by read_files
+function read_parquet(path::String; how::Symbol=:vertical, include_file_paths=nothing)::DataFrame
+  if how === :vertical && include_file_paths === nothing && !occursin(r"[*?\[]", path) && !isdir(path)
+    return FFI.polars_dataframe_read_parquet(path)
+  end
+  return read_files(path; format=:parquet, how, include_file_paths)
+end
+read_parquet(paths::AbstractVector; kwargs...)::DataFrame = read_files(paths; format=:parquet, kwargs...)
+read_csv(paths; kwargs...)::DataFrame = read_files(paths; format=:csv, kwargs...)
+read_ipc(paths; kwargs...)::DataFrame = read_files(paths; format=:ipc, kwargs...)
+# partitions estimated at more than this many bytes in memory are split over several files,
+# 4 GiB as in python polars so that a typical partition stays one file that readers can skip whole
+const PARTITION_CHUNK_SIZE = 4 * UInt64(1024)^3
+# with partition_by, path is a directory with one key=value subdirectory per partition
+function write_parquet(df::DataFrame, path::String; partition_by=nothing, chunk_size::Integer=PARTITION_CHUNK_SIZE)::Nothing
+  partition_by === nothing && return FFI.polars_dataframe_write_parquet(df.inner, path)
+  return FFI.polars_dataframe_write_parquet_partitioned(df.inner, path, _names(partition_by), UInt64(chunk_size))
+end
 get_column(df::DataFrame, name::String)::Column = FFI.polars_dataframe_get_column(df.inner, name)
+# how can be :vertical, :vertical_relaxed, :horizontal, :diagonal, :diagonal_relaxed
+concat(dfs::AbstractVector{DataFrame}; how::Symbol=:vertical, rechunk::Bool=false)::DataFrame = FFI.polars_dataframe_concat([df.inner for df in dfs], how, rechunk)
+# casts the columns named in dtypes, e.g. cast(df, Dict("a" => DataTypes.Int32()))
+function cast(df::DataFrame, dtypes; strict::Bool=true)::DataFrame
+  names = String[first(p) for p in dtypes]
+  return FFI.polars_dataframe_cast(df.inner, names, [intoraw(last(p)) for p in dtypes], strict)
+end
+# one row per element of the List or Array columns names, the other values are repeated
+explode(df::DataFrame, names::AbstractString...)::DataFrame = FFI.polars_dataframe_explode(df.inner, String[names...])
+# a single row holding every column as a List
+implode(df::DataFrame)::DataFrame = FFI.polars_dataframe_implode(df.inner)
+
+_names(name::AbstractString) = [String(name)]
+_names(names) = String[names...]
+
+"""
+    pivot(df; on, index=String[], values=String[], aggregate_function=nothing, sort_columns=false, separator="_")
+
+Reshapes `df` to wide format with one column per unique value of `on`, holding `values`
+for each row of `index`. An empty `index` or `values` takes all remaining columns.
+`aggregate_function` combines repeated entries and is one of `:first`, `:last`, `:sum`,
+`:mean`, `:median`, `:min`, `:max` or `:len`; with `nothing` they raise an error.
+"""
+function pivot(df::DataFrame; on, index=String[], values=String[], aggregate_function::Union{Symbol,Nothing}=nothing,
+               sort_columns::Bool=false, separator::AbstractString="_")::DataFrame
+  return FFI.polars_dataframe_pivot(df.inner, _names(on), _names(index), _names(values), aggregate_function, sort_columns, String(separator))
+end
+
+"""
+    unpivot(df; on=String[], index=String[], variable_name="variable", value_name="value")
+
+Reshapes `df` to long format, stacking the `on` columns into `variable_name` (their names)
+and `value_name` (their values) and repeating the `index` columns. An empty `on` takes
+all columns not in `index`.
+"""
+function unpivot(df::DataFrame; on=String[], index=String[], variable_name::AbstractString="variable", value_name::AbstractString="value")::DataFrame
+  return FFI.polars_dataframe_unpivot(df.inner, _names(on), _names(index), String(variable_name), String(value_name))
+end
+
+# the rows unique over the subset columns (all if empty), keep can be :first, :last, :none or :any
+Base.unique(df::DataFrame; subset=String[], keep::Symbol=:first)::DataFrame = FFI.polars_dataframe_unique(df.inner, _names(subset), keep, false)
+# like unique, keeping the order of the rows
+unique_stable(df::DataFrame; subset=String[], keep::Symbol=:first)::DataFrame = FFI.polars_dataframe_unique(df.inner, _names(subset), keep, true)
+is_duplicated(df::DataFrame; subset=String[])::Column = FFI.polars_dataframe_is_duplicated(df.inner, _names(subset))
+is_unique(df::DataFrame; subset=String[])::Column = FFI.polars_dataframe_is_unique(df.inner, _names(subset))
+n_unique(df::DataFrame; subset=String[])::Int = FFI.polars_dataframe_n_unique(df.inner, _names(subset))
+# the rows without nulls in the subset columns (all if empty)
+drop_nulls(df::DataFrame; subset=String[])::DataFrame = FFI.polars_dataframe_drop_nulls(df.inner, _names(subset))
+# all values of row idx as a NamedTuple
+row(df::DataFrame, idx::Integer)::NamedTuple = FFI.polars_value_extract(FFI.polars_dataframe_get_row(df.inner, convert(UInt, idx) - 1))
+# the single row where the Bool column by_predicate is true
+row(df::DataFrame; by_predicate::Column)::NamedTuple = FFI.polars_value_extract(FFI.polars_dataframe_row_by_predicate(df.inner, by_predicate.inner))
+
+struct Rows
+  df::DataFrame
+  batch::Int
+end
+# iterates the rows of df as NamedTuples, converting batch rows at a time
+function rows(df::DataFrame; batch::Integer=1024)::Rows
+  batch >= 1 || throw(ArgumentError("batch must be at least 1, got $batch"))
+  return Rows(df, batch)
+end
+Base.length(r::Rows) = Int(height(r.df))
+Base.eltype(::Type{Rows}) = NamedTuple
+function Base.iterate(r::Rows, (buffer, i, offset)=(NamedTuple[], 1, 0))
+  if i > length(buffer)
+    offset >= length(r) && return nothing
+    buffer = FFI.polars_dataframe_rows(r.df.inner, offset, r.batch)
+    i, offset = 1, offset + length(buffer)
+  end
+  return buffer[i], (buffer, i + 1, offset)
+end
 
 Column(name::String; dtype::DataType=DataTypes.Int64())::Column = FFI.polars_column_new_empty(name, intoraw(dtype))
+function Column(name::String, values::AbstractVector)::Column
+  T = Base.nonnothingtype(nonmissingtype(eltype(values)))
+  isvalid(v) = !(isnothing(v) || ismissing(v))
+  validity = T === eltype(values) ? Bool[] : isvalid.(values)
+  if T === Any
+    # e.g. vectors mixed with nothing, the element type follows the values
+    T = mapreduce(typeof, typejoin, Iterators.filter(isvalid, values); init=Union{})
+    validity = isvalid.(values)
+  end
+  if T !== Union{} && T <: AbstractVector && !(T <: AbstractVector{UInt8})
+    # lists are passed as a column of the flattened values plus offsets, as in arrow
+    lists = [isvalid(v) ? v : [] for v in values]
+    flat = [x for v in lists for x in v]
+    inner = isempty(flat) ? Column(name; dtype=DataTypes.fromjulia(Base.nonnothingtype(nonmissingtype(eltype(T))))) : Column(name, flat)
+    offsets = cumsum([0; length.(lists)])
+    return FFI.polars_column_from_list(name, inner.inner, offsets, validity)
+  elseif T <: Union{AbstractString, AbstractVector{UInt8}}
+    # variable width values are passed as one buffer plus offsets
+    bytes = [!isvalid(v) ? UInt8[] : v isa AbstractString ? codeunits(v) : v for v in values]
+    offsets = cumsum([0; length.(bytes)])
+    data = UInt8[b for v in bytes for b in v]
+    return FFI.polars_column_from_bytes(name, intoraw(DataTypes.fromjulia(T)), data, offsets, validity)
+  elseif T <: Decimal
+    scale = maximum(v.scale for v in values if isvalid(v); init=0)
+    bits = [isvalid(v) ? Decimals.rescale(v, scale).value : Int128(0) for v in values]
+    dtype = DataTypes.Decimal(38, scale)
+  elseif T <: Dates.Date
+    # temporal values are passed as their polars physical integers
+    bits = Int32[isvalid(v) ? Dates.value(v - Dates.Date(1970)) : 0 for v in values]
+    dtype = DataTypes.Date()
+  elseif T <: Dates.DateTime
+    bits = Int64[isvalid(v) ? Dates.value(v - Dates.DateTime(1970)) : 0 for v in values]
+    dtype = DataTypes.DateTime{:ms}(nothing)
+  elseif T <: Dates.Time
+    bits = Int64[isvalid(v) ? Dates.value(v) : 0 for v in values]
+    dtype = DataTypes.Time{:μs}()
+  elseif T <: Union{Dates.Nanosecond, Dates.Microsecond, Dates.Millisecond}
+    bits = Int64[isvalid(v) ? Dates.value(v) : 0 for v in values]
+    dtype = DataTypes.Duration{T <: Dates.Nanosecond ? :ns : T <: Dates.Microsecond ? :μs : :ms}()
+  elseif T <: Timestamp
+    i = findfirst(isvalid, values)
+    unit, time_zone = isnothing(i) ? (:ms, nothing) : (values[i].unit, values[i].time_zone)
+    all(v -> !isvalid(v) || (v.unit === unit && v.time_zone == time_zone), values) ||
+      throw(ArgumentError("timestamps in a column must share unit and time zone"))
+    bits = Int64[isvalid(v) ? v.value : 0 for v in values]
+    dtype = DataTypes.DateTime{unit}(time_zone)
+  else
+    bits = T[isvalid(v) ? v : zero(T) for v in values]
+    dtype = DataTypes.fromjulia(T)
+  end
+  return FFI.polars_column_from_bits(name, intoraw(dtype), collect(reinterpret(UInt8, bits)), validity)
+end
 Base.convert(::Type{Column}, col::FFI.polars_column_t) = Column(col)
 Base.unsafe_convert(::Type{FFI.polars_column_t}, col::Column) = col.inner
 Base.length(col::Column) = FFI.polars_column_len(col.inner)
 Base.getindex(col::Column, idx::Integer)::Any = FFI.polars_column_get(col.inner, convert(UInt, idx) - 1) |> FFI.polars_value_extract
 Base.getindex(col::Column, range) = Base.getindex.(Ref(col), range)
-dtype(col::Column)::DataType = FFI.polars_column_dtype(col.inner)
+Base.collect(col::Column)::Vector = FFI.polars_column_to_vector(col.inner)
+# offsets (0-based, length + 1 entries) and flattened values of a List or Array column
+list_offsets(col::Column)::Vector{Int64} = FFI.polars_column_list_offsets(col.inner)
+list_values(col::Column)::Column = FFI.polars_column_list_values(col.inner)
+# all values as a List column of length 1
+implode(col::Column)::Column = FFI.polars_column_implode(col.inner)
+# 1-based refs (0 for null) into the pool of a Categorical or Enum column, as in CategoricalArrays
+categorical_parts(col::Column)::Tuple{Vector{UInt32}, Vector{String}} = FFI.polars_column_categorical_parts(col.inner)
+# same instants in another zone
+convert_time_zone(col::Column, time_zone::String)::Column = FFI.polars_column_convert_time_zone(col.inner, time_zone)
+# same wall time in another zone, nothing makes the column naive
+replace_time_zone(col::Column, time_zone::Union{String, Nothing})::Column = FFI.polars_column_replace_time_zone(col.inner, time_zone)
+# unit can be :ns, :μs, :ms
+cast_time_unit(col::Column, unit::Symbol)::Column = FFI.polars_column_cast_time_unit(col.inner, unit)
+# a strict cast throws on values that can't be converted, otherwise they become null
+cast(col::Column, dtype::DataType; strict::Bool=true)::Column = FFI.polars_column_cast(col.inner, intoraw(dtype), strict)
+function dtype(col::Column)::DataType
+  dt = FFI.polars_column_dtype(col.inner)
+  dt isa DataTypes.Categorical || return dt
+  # the dtype alone lists every category of its shared mapping, a column lists its own
+  return DataTypes.Categorical(dt.name, dt.namespace, dt.physical, categorical_parts(col)[2])
+end
 name(col::Column)::String = FFI.polars_column_name(col.inner)
 null_count(col::Column)::UInt = FFI.polars_column_null_count(col.inner)
 
+# elementwise kernels, a scalar operand is sent as a column of length 1
+# durations are only ns, μs or ms, calendar periods like Day have no fixed length
+const Scalar = Union{Number, AbstractString, Decimal, Dates.TimeType, Dates.Nanosecond, Dates.Microsecond,
+                     Dates.Millisecond, Timestamp}
+const _IntDTypes = Union{DataTypes.Int8, DataTypes.Int16, DataTypes.Int32, DataTypes.Int64, DataTypes.Int128,
+                         DataTypes.UInt8, DataTypes.UInt16, DataTypes.UInt32, DataTypes.UInt64}
+const _FloatDTypes = Union{DataTypes.Float32, DataTypes.Float64}
+# a number takes the dtype of the column when it fits exactly, so Int32 + 1 stays Int32
+function _literal(col::Column, x)
+  lit = Column(name(col), [x])
+  dt = dtype(col)
+  fits = x isa Bool ? false : (dt isa _IntDTypes && x isa Integer) || (dt isa _FloatDTypes && x isa Real)
+  fits || return lit
+  narrow = cast(lit, dt; strict=false)
+  return isequal(narrow[1], x) ? narrow : lit
+end
+binary(a::Column, b::Column, op::Symbol)::Column = FFI.polars_column_binary(a.inner, b.inner, op)
+for (f, op) in ((:+, :add), (:-, :sub), (:*, :mul), (:/, :div), (:rem, :rem), (:&, :and), (:|, :or))
+  @eval Base.$f(a::Column, b::Column) = binary(a, b, $(QuoteNode(op)))
+  @eval Base.$f(a::Column, b::Scalar) = binary(a, _literal(a, b), $(QuoteNode(op)))
+  @eval Base.$f(a::Scalar, b::Column) = binary(_literal(b, a), b, $(QuoteNode(op)))
+end
+Base.:-(a::Column) = binary(_literal(a, 0), a, :sub)
+# comparisons only broadcast, `==` keeps comparing whole columns: col .> 1
+for (f, op) in ((:+, :add), (:-, :sub), (:*, :mul), (:/, :div), (:rem, :rem), (:&, :and), (:|, :or),
+                (:(==), :eq), (:!=, :ne), (:<, :lt), (:<=, :le), (:>, :gt), (:>=, :ge))
+  @eval Base.broadcasted(::typeof($f), a::Column, b::Column) = binary(a, b, $(QuoteNode(op)))
+  @eval Base.broadcasted(::typeof($f), a::Column, b::Scalar) = binary(a, _literal(a, b), $(QuoteNode(op)))
+  @eval Base.broadcasted(::typeof($f), a::Scalar, b::Column) = binary(_literal(b, a), b, $(QuoteNode(op)))
+end
+
+# reductions run in polars and return julia values
+_reduce(col::Column, op::Symbol) = FFI.polars_value_extract(FFI.polars_column_reduce(col.inner, op))
+Base.sum(col::Column) = _reduce(col, :sum)
+Base.prod(col::Column) = _reduce(col, :product)
+Base.minimum(col::Column) = _reduce(col, :min)
+Base.maximum(col::Column) = _reduce(col, :max)
+Base.any(col::Column) = _reduce(col, :any)
+Base.all(col::Column) = _reduce(col, :all)
+n_unique(col::Column)::Int = _reduce(col, :n_unique)
+Statistics.mean(col::Column) = _reduce(col, :mean)
+Statistics.median(col::Column) = _reduce(col, :median)
+Statistics.std(col::Column; corrected::Bool=true) = FFI.polars_value_extract(FFI.polars_column_std(col.inner, corrected ? 1 : 0))
+Statistics.var(col::Column; corrected::Bool=true) = FFI.polars_value_extract(FFI.polars_column_var(col.inner, corrected ? 1 : 0))
+# method can be :nearest, :lower, :higher, :midpoint, :linear, :equiprobable
+Statistics.quantile(col::Column, p::Real; method::Symbol=:linear) = FFI.polars_value_extract(FFI.polars_column_quantile(col.inner, p, method))
+is_duplicated(col::Column)::Column = FFI.polars_column_is_duplicated(col.inner)
+is_unique(col::Column)::Column = FFI.polars_column_is_unique(col.inner)
+# a frame of the unique values and their counts, sorted by descending count if sort,
+# counts are fractions of the column length if normalize
+value_counts(col::Column; sort::Bool=false, normalize::Bool=false, name::AbstractString=normalize ? "proportion" : "count")::DataFrame =
+  FFI.polars_column_value_counts(col.inner, sort, normalize, String(name))
+# replaces the nulls by value, the column is cast to the supertype if needed
+fill_null(col::Column, value)::Column = FFI.polars_column_fill_null(col.inner, Value(value).inner)
+# strategy can be :forward, :backward, :mean, :min, :max, :zero, :one,
+# limit bounds the number of consecutive nulls filled by :forward and :backward
+function fill_null(col::Column; strategy::Symbol, limit::Union{Integer, Nothing}=nothing)::Column
+  limit = isnothing(limit) ? nothing : convert(UInt, limit)
+  return FFI.polars_column_fill_null_with_strategy(col.inner, strategy, limit)
+end
+fill_nan(col::Column, value)::Column = FFI.polars_column_fill_nan(col.inner, Value(value).inner)
+is_nan(col::Column)::Column = FFI.polars_column_is_nan(col.inner)
+# fills the nulls between two values, method can be :linear or :nearest
+interpolate(col::Column; method::Symbol=:linear)::Column = FFI.polars_column_interpolate(col.inner, method)
+
+"""
+    Value(x)
+
+Polars scalar for a julia value: numbers, `Bool`, strings, byte vectors (Binary), `Decimal`,
+`Dates` values (periods up to milliseconds) and `Timestamp`. `nothing` and `missing` become
+null, other vectors a List and NamedTuples a Struct. `v[]` extracts the julia value again.
+"""
+struct Value
+  inner::FFI.polars_value_t
+end
+
+Value(v::Value) = v
+Value(::Union{Nothing, Missing}) = Value(FFI.polars_value_new_null())
+Value(x::AbstractString) = Value(FFI.polars_value_new_string(String(x)))
+Value(x::AbstractVector{UInt8}) = Value(FFI.polars_value_new_binary(Vector{UInt8}(x)))
+# other scalars are passed as their dtype and physical bits, the same ones Column passes
+function Value(x::Union{Bool, Int8, Int16, Int32, Int64, Int128, UInt8, UInt16, UInt32, UInt64, Float32, Float64, Decimal,
+                        Dates.Date, Dates.DateTime, Dates.Time, Dates.Nanosecond, Dates.Microsecond, Dates.Millisecond, Timestamp})
+  dtype, bits = _scalar_bits(x)
+  return Value(FFI.polars_value_from_bits(intoraw(dtype), (bits >> 64) % Int64, bits % UInt64))
+end
+_scalar_bits(x::Union{Bool, Int8, Int16, Int32, Int64, Int128, UInt8, UInt16, UInt32, UInt64})::Tuple{DataType, Int128} =
+  (DataTypes.fromjulia(typeof(x)), Int128(x))
+_scalar_bits(x::Float32)::Tuple{DataType, Int128} = (DataTypes.Float32(), Int128(reinterpret(UInt32, x)))
+_scalar_bits(x::Float64)::Tuple{DataType, Int128} = (DataTypes.Float64(), Int128(reinterpret(UInt64, x)))
+_scalar_bits(x::Decimal)::Tuple{DataType, Int128} = (DataTypes.Decimal(38, x.scale), x.value)
+_scalar_bits(x::Dates.Date)::Tuple{DataType, Int128} = (DataTypes.Date(), Dates.value(x - Dates.Date(1970)))
+_scalar_bits(x::Dates.DateTime)::Tuple{DataType, Int128} = (DataTypes.DateTime{:ms}(nothing), Dates.value(x - Dates.DateTime(1970)))
+_scalar_bits(x::Dates.Time)::Tuple{DataType, Int128} = (DataTypes.Time{:μs}(), Dates.value(x))
+_scalar_bits(x::Dates.Nanosecond)::Tuple{DataType, Int128} = (DataTypes.Duration{:ns}(), Dates.value(x))
+_scalar_bits(x::Dates.Microsecond)::Tuple{DataType, Int128} = (DataTypes.Duration{:μs}(), Dates.value(x))
+_scalar_bits(x::Dates.Millisecond)::Tuple{DataType, Int128} = (DataTypes.Duration{:ms}(), Dates.value(x))
+_scalar_bits(x::Timestamp)::Tuple{DataType, Int128} = (DataTypes.DateTime{x.unit}(x.time_zone), x.value)
+Value(v::AbstractVector) = Value(FFI.polars_value_new_list(FFI.polars_value_t[Value(x).inner for x in v]))
+Value(nt::NamedTuple) = Value(FFI.polars_value_new_struct(String[string(k) for k in keys(nt)], FFI.polars_value_t[Value(x).inner for x in values(nt)]))
+Base.convert(::Type{Value}, v::FFI.polars_value_t) = Value(v)
+Base.getindex(v::Value) = FFI.polars_value_extract(v.inner)
+dtype(v::Value)::DataType = FFI.polars_value_dtype(v.inner)
+
 function Base.convert(::Type{DataType}, dtype::FFI.polars_value_type_t)::DataType
   sym = FFI.polars_value_type_symbol(dtype)
   kwargs = FFI.polars_value_type_kwargs(dtype)
@@ -58,4 +352,95 @@ end
 intoraw(dtype::DataType)::polars_value_type_t = DataTypes.intoraw(dtype)
 intoraw(dtype::polars_value_type_t)::polars_value_type_t = dtype
 
+include("strings.jl")
+include("temporal.jl")
+include("expr.jl")
+include("lists.jl")
+include("sql.jl")
+
+import .Exprs: col, lit
+import .SQL: SQLContext
+# a frame of the columns computed by exprs
+select(df::DataFrame, exprs::Exprs.Expr...)::DataFrame = FFI.polars_dataframe_select(df.inner, FFI.polars_expr_t[e.inner for e in exprs])
+# adds the columns computed by exprs, replacing columns with the same name
+with_columns(df::DataFrame, exprs::Exprs.Expr...)::DataFrame = FFI.polars_dataframe_with_columns(df.inner, FFI.polars_expr_t[e.inner for e in exprs])
+
+# durations are interval strings like "1h30m" ("3i" counts rows of an integer index) or Dates periods
+const INTERVAL_UNITS = Dict(Dates.Nanosecond => "ns", Dates.Microsecond => "μs", Dates.Millisecond => "ms",
+                            Dates.Second => "s", Dates.Minute => "m", Dates.Hour => "h", Dates.Day => "d",
+                            Dates.Week => "w", Dates.Month => "mo", Dates.Quarter => "q", Dates.Year => "y")
+interval(s::AbstractString)::String = String(s)
+interval(p::Dates.Period)::String = string(Dates.value(p), INTERVAL_UNITS[typeof(p)])
+# polars only takes a sign in front of the whole interval, so the parts must agree in sign
+function interval(p::Dates.CompoundPeriod)::String
+  isempty(p.periods) && return "0ns"
+  all(x -> Dates.value(x) >= 0, p.periods) && return join(interval.(p.periods))
+  all(x -> Dates.value(x) <= 0, p.periods) && return "-" * join(interval.(.-p.periods))
+  throw(ArgumentError("the parts of an interval must have the same sign, got $p"))
+end
+_negate(s::String)::String = startswith(s, "-") ? s[2:end] : "-" * s
+
+"""
+    rolling(df, index_column, aggs...; period, offset=-period, closed=:right, group_by=String[])
+
+For every row, aggregates `aggs` over the rows whose `index_column` lies in the window
+`(t + offset, t + offset + period]`, `closed` can be `:left`, `:right`, `:both` or `:none`.
+`index_column` must be sorted, within each group of `group_by` if given.
+"""
+function rolling(df::DataFrame, index_column::AbstractString, aggs::Exprs.Expr...; period, offset=nothing, closed::Symbol=:right, group_by=String[])::DataFrame
+  offset = isnothing(offset) ? _negate(interval(period)) : interval(offset)
+  return FFI.polars_dataframe_rolling(df.inner, String(index_column), interval(period), offset, closed, String[group_by...], FFI.polars_expr_t[e.inner for e in aggs])
+end
+
+"""
+    group_by_dynamic(df, index_column, aggs...; every, period=every, offset="0ns", closed=:left,
+                     label=:left, start_by=:window, include_boundaries=false, group_by=String[])
+
+Aggregates `aggs` over windows that start every `every` along the sorted `index_column`,
+each `period` long and shifted by `offset`. `label` picks the value shown for a window
+(`:left`, `:right` or `:datapoint`), `start_by` where the first window starts (`:window`,
+`:datapoint` or a weekday like `:monday`).
+"""
+function group_by_dynamic(df::DataFrame, index_column::AbstractString, aggs::Exprs.Expr...; every, period=every, offset="0ns",
+                          closed::Symbol=:left, label::Symbol=:left, start_by::Symbol=:window, include_boundaries::Bool=false, group_by=String[])::DataFrame
+  return FFI.polars_dataframe_group_by_dynamic(df.inner, String(index_column), interval(every), interval(period), interval(offset),
+    closed, label, start_by, include_boundaries, String[group_by...], FFI.polars_expr_t[e.inner for e in aggs])
+end
+
+Base.convert(::Type{LazyFrame}, lf::FFI.polars_lazyframe_t) = LazyFrame(lf)
+Base.show(io::IO, lf::LazyFrame) = print(io, explain(lf; optimized=false))
+lazy(df::DataFrame)::LazyFrame = FFI.polars_dataframe_lazy(df.inner)
+"""
+    scan_parquet(path; hive_partitioning=nothing, try_parse_hive_dates=true, include_file_paths=nothing)
+
+Scans a parquet file, a directory or a glob like "data/*.parquet".
+`key=value` directories on the path become partition columns, by default only when `path` is a directory.
+Filters on those columns skip the files of other partitions.
+`include_file_paths` names a column holding the path of the file each row was read from.
+"""
+function scan_parquet(path::AbstractString; hive_partitioning::Union{Bool, Nothing}=nothing, try_parse_hive_dates::Bool=true,
+                      include_file_paths::Union{AbstractString, Nothing}=nothing)::LazyFrame
+  include_file_paths = include_file_paths === nothing ? nothing : String(include_file_paths)
+  return FFI.polars_lazyframe_scan_parquet(String(path), hive_partitioning, try_parse_hive_dates, include_file_paths)
+end
+scan_csv(path::AbstractString)::LazyFrame = FFI.polars_lazyframe_scan_csv(String(path))
+scan_ipc(path::AbstractString)::LazyFrame = FFI.polars_lazyframe_scan_ipc(String(path))
+filter(lf::LazyFrame, predicate::Exprs.Expr)::LazyFrame = FFI.polars_lazyframe_filter(lf.inner, predicate.inner)
+select(lf::LazyFrame, exprs::Exprs.Expr...)::LazyFrame = FFI.polars_lazyframe_select(lf.inner, FFI.polars_expr_t[e.inner for e in exprs])
+with_columns(lf::LazyFrame, exprs::Exprs.Expr...)::LazyFrame = FFI.polars_lazyframe_with_columns(lf.inner, FFI.polars_expr_t[e.inner for e in exprs])
+# one row per unique combination of the by columns, in order of first appearance if maintain_order
+group_by(lf::LazyFrame, by, aggs::Exprs.Expr...; maintain_order::Bool=false)::LazyFrame =
+  FFI.polars_lazyframe_group_by(lf.inner, _names(by), FFI.polars_expr_t[e.inner for e in aggs], maintain_order)
+Base.sort(lf::LazyFrame, by::AbstractString...; rev::Bool=false)::LazyFrame = FFI.polars_lazyframe_sort(lf.inner, String[by...], rev)
+Base.first(lf::LazyFrame, n::Integer)::LazyFrame = FFI.polars_lazyframe_limit(lf.inner, convert(UInt, n))
+# the query plan, after optimizations like predicate pushdown if optimized
+explain(lf::LazyFrame; optimized::Bool=true)::String = FFI.polars_lazyframe_explain(lf.inner, optimized)
+# runs the query, the streaming engine processes the input in batches instead of loading it at once
+Base.collect(lf::LazyFrame; streaming::Bool=false)::DataFrame = FFI.polars_lazyframe_collect(lf.inner, streaming)
+
+# the sinks run the query on the streaming engine and write the result batch by batch
+sink_parquet(lf::LazyFrame, path::AbstractString)::Nothing = FFI.polars_lazyframe_sink_parquet(lf.inner, String(path))
+sink_ipc(lf::LazyFrame, path::AbstractString)::Nothing = FFI.polars_lazyframe_sink_ipc(lf.inner, String(path))
+sink_csv(lf::LazyFrame, path::AbstractString)::Nothing = FFI.polars_lazyframe_sink_csv(lf.inner, String(path))
+
 end # module Polars
@@ -9,16 +9,19 @@ struct Int8 <: DataType end
 struct Int16 <: DataType end
 struct Int32 <: DataType end
 struct Int64 <: DataType end
+struct Int128 <: DataType end
 struct UInt8 <: DataType end
 struct UInt16 <: DataType end
 struct UInt32 <: DataType end
 struct UInt64 <: DataType end
 struct Float32 <: DataType end
 struct Float64 <: DataType end
+struct String <: DataType end
+struct Binary <: DataType end
 struct Decimal{P, S} <: DataType end
 # unit can be :ns, :μs, :ms
 struct DateTime{U} <: DataType
-  time_zone::Union{String, Nothing}
+  time_zone::Union{Base.String, Nothing}
 end
 struct Date <: DataType end
 # unit must be :μs
@@ -31,6 +34,23 @@ end
 struct Array{T<:DataType, N} <: DataType
   inner::T
 end
+# fields is a NamedTuple of field name => DataType
+struct Struct{T<:NamedTuple} <: DataType
+  fields::T
+end
+# categories in order of first appearance, they sort lexically. The dtype of a column lists its own
+# categories, any other Categorical dtype all categories seen so far in its namespace.
+# physical can be :u8, :u16, :u32
+struct Categorical <: DataType
+  name::Base.String
+  namespace::Base.String
+  physical::Symbol
+  categories::Vector{Base.String}
+end
+# fixed categories, their order is the sort order
+struct Enum <: DataType
+  categories::Vector{Base.String}
+end
 struct Unknown <: DataType
   tag::Symbol
   inner::polars_value_type_t
@@ -45,6 +65,15 @@ timeunit(::DateTime{U}) where {U} = U
 timeunit(::Time{U}) where {U} = U
 timeunit(::Duration{U}) where {U} = U
 arraysize(::Array{T, N}) where {T<:DataType, N} = N
+Categorical(; name="", namespace="", physical=:u32)::DataType = Categorical(name, namespace, physical, Base.String[])
+categories(dtype::Union{Categorical, Enum}) = dtype.categories
+ordering(::Categorical) = :lexical
+ordering(::Enum) = :physical
+# the categories of a Categorical are only a snapshot, so they are left out
+Base.:(==)(a::Categorical, b::Categorical) = (a.name, a.namespace, a.physical) == (b.name, b.namespace, b.physical)
+Base.hash(dtype::Categorical, h::UInt) = hash((dtype.name, dtype.namespace, dtype.physical), hash(:Categorical, h))
+Base.:(==)(a::Enum, b::Enum) = a.categories == b.categories
+Base.hash(dtype::Enum, h::UInt) = hash(dtype.categories, hash(:Enum, h))
 
 function DataType(sym::Symbol; kwargs...)::DataType
   if sym === :Null
@@ -59,6 +88,8 @@ function DataType(sym::Symbol; kwargs...)::DataType
     return Int32()
   elseif sym === :Int64
     return Int64()
+  elseif sym === :Int128
+    return Int128()
   elseif sym === :UInt8
     return UInt8()
   elseif sym === :UInt16
@@ -71,6 +102,10 @@ function DataType(sym::Symbol; kwargs...)::DataType
     return Float32()
   elseif sym === :Float64
     return Float64()
+  elseif sym === :String
+    return String()
+  elseif sym === :Binary
+    return Binary()
   elseif sym === :Decimal
     # TODO: default precision and scale
     precision = something(get(kwargs, :precision, nothing), 10)
@@ -95,6 +130,16 @@ function DataType(sym::Symbol; kwargs...)::DataType
     inner = convert(DataType, kwargs[:inner])
     n = convert(Int, kwargs[:size])
     return Array(inner, n)
+  elseif sym === :Struct
+    fields = map(f -> convert(DataType, f), kwargs[:fields])
+    return Struct(fields)
+  elseif sym === :Categorical
+    name = get(kwargs, :name, "")
+    namespace = get(kwargs, :namespace, "")
+    physical = get(kwargs, :physical, :u32)
+    return Categorical(name, namespace, physical, get(kwargs, :categories, Base.String[]))
+  elseif sym === :Enum
+    return Enum(kwargs[:categories])
   else
     throw(ArgumentError("Unimplemented data type symbol: $sym"))
   end
@@ -112,6 +157,8 @@ function type(dtype::DataType)::Symbol
     return :Int32
   elseif dtype isa Int64
     return :Int64
+  elseif dtype isa Int128
+    return :Int128
   elseif dtype isa UInt8
     return :UInt8
   elseif dtype isa UInt16
@@ -124,6 +171,10 @@ function type(dtype::DataType)::Symbol
     return :Float32
   elseif dtype isa Float64
     return :Float64
+  elseif dtype isa String
+    return :String
+  elseif dtype isa Binary
+    return :Binary
   elseif dtype isa Decimal
     return :Decimal
   elseif dtype isa DateTime
@@ -138,6 +189,12 @@ function type(dtype::DataType)::Symbol
     return :List
   elseif dtype isa Array
     return :Array
+  elseif dtype isa Struct
+    return :Struct
+  elseif dtype isa Categorical
+    return :Categorical
+  elseif dtype isa Enum
+    return :Enum
   elseif dtype isa Unknown
     return dtype.tag
   else
@@ -157,12 +214,52 @@ function kwargs(dtype::DataType)::NamedTuple
     return (; inner=dtype.inner,)
   elseif dtype isa Array
     return (; inner=dtype.inner, size=arraysize(dtype),)
+  elseif dtype isa Struct
+    return (; fields=dtype.fields,)
+  elseif dtype isa Categorical
+    return (; name=dtype.name, namespace=dtype.namespace, physical=dtype.physical)
+  elseif dtype isa Enum
+    return (; categories=dtype.categories,)
   elseif dtype isa Unknown
     return ()
   else
     return ()
   end
 end
+# polars dtype for a julia element type
+function fromjulia(T::Type)::DataType
+  if T === Base.Bool
+    return Boolean()
+  elseif T === Base.Int8
+    return Int8()
+  elseif T === Base.Int16
+    return Int16()
+  elseif T === Base.Int32
+    return Int32()
+  elseif T === Base.Int64
+    return Int64()
+  elseif T === Base.Int128
+    return Int128()
+  elseif T === Base.UInt8
+    return UInt8()
+  elseif T === Base.UInt16
+    return UInt16()
+  elseif T === Base.UInt32
+    return UInt32()
+  elseif T === Base.UInt64
+    return UInt64()
+  elseif T === Base.Float32
+    return Float32()
+  elseif T === Base.Float64
+    return Float64()
+  elseif T <: AbstractString
+    return String()
+  elseif T <: AbstractVector{Base.UInt8}
+    return Binary()
+  else
+    throw(ArgumentError("No polars data type for julia type: $T"))
+  end
+end
 intoraw(dtype::DataType)::polars_value_type_t = polars_value_type_from_name_and_kwargs(type(dtype), kwargs(dtype))
 
 end # module DataTypes
@@ -0,0 +1,67 @@
+module Decimals
+
+"""
+    Decimal(value::Int128, scale::Int)
+
+Exact decimal number `value * 10^-scale`, the same representation polars uses.
+"""
+struct Decimal
+  value::Int128
+  scale::Int
+end
+
+Decimal(value::Integer, scale::Integer) = Decimal(Int128(value), Int(scale))
+Decimal(value::Integer) = Decimal(value, 0)
+function Base.parse(::Type{Decimal}, s::AbstractString)::Decimal
+  m = match(r"^([+-]?\d+)(?:\.(\d+))?$", strip(s))
+  m === nothing && throw(ArgumentError("invalid decimal: $s"))
+  frac = something(m[2], "")
+  return Decimal(parse(Int128, m[1] * frac), length(frac))
+end
+
+"""
+    rescale(d::Decimal, scale::Int)
+
+Same value with another scale, throws `InexactError` if digits would be lost.
+"""
+function rescale(d::Decimal, scale::Integer)::Decimal
+  if scale >= d.scale
+    return Decimal(d.value * Int128(10)^(scale - d.scale), scale)
+  end
+  q, r = divrem(d.value, Int128(10)^(d.scale - scale))
+  r == 0 || throw(InexactError(:rescale, Decimal, d))
+  return Decimal(q, scale)
+end
+
+# strip trailing zeros so that equal values compare and hash equal
+function normalize(d::Decimal)::Decimal
+  value, scale = d.value, d.scale
+  while scale > 0 && value % 10 == 0
+    value ÷= 10
+    scale -= 1
+  end
+  return Decimal(value, scale)
+end
+
+# compared in normalized form, rescaling both to the larger scale could overflow
+function Base.:(==)(a::Decimal, b::Decimal)
+  a, b = normalize(a), normalize(b)
+  return a.value == b.value && a.scale == b.scale
+end
+Base.hash(d::Decimal, h::UInt) = hash(normalize(d).value, hash(normalize(d).scale, hash(Decimal, h)))
+Base.Rational(d::Decimal) = d.value // Int128(10)^d.scale
+Base.Float64(d::Decimal) = Float64(Rational(d))
+Base.convert(::Type{Float64}, d::Decimal) = Float64(d)
+Base.zero(::Type{Decimal}) = Decimal(0)
+
+function Base.show(io::IO, d::Decimal)
+  digits = lpad(string(abs(d.value)), d.scale + 1, '0')
+  sign = d.value < 0 ? "-" : ""
+  if d.scale == 0
+    print(io, sign, digits)
+  else
+    print(io, sign, digits[1:end-d.scale], ".", digits[end-d.scale+1:end])
+  end
+end
+
+end # module Decimals
@@ -0,0 +1,77 @@
+module Errors
+
+import ..FFI: polars_error_t, polars_error_message, polars_error_kind, polars_error_function
+
+# polars and IO errors and invalid arguments such as an unknown option symbol, thrown as the
+# subtype matching their kind. Only panics and errors of the bindings are a JlrsCore.JlrsError.
+abstract type PolarsError <: Exception end
+struct ColumnNotFoundError <: PolarsError
+  inner::polars_error_t
+end
+struct SchemaMismatchError <: PolarsError
+  inner::polars_error_t
+end
+struct ShapeMismatchError <: PolarsError
+  inner::polars_error_t
+end
+struct ComputeError <: PolarsError
+  inner::polars_error_t
+end
+struct InvalidOperationError <: PolarsError
+  inner::polars_error_t
+end
+struct DuplicateError <: PolarsError
+  inner::polars_error_t
+end
+struct OutOfBoundsError <: PolarsError
+  inner::polars_error_t
+end
+struct NoDataError <: PolarsError
+  inner::polars_error_t
+end
+struct IOError <: PolarsError
+  inner::polars_error_t
+end
+# an argument polars can't use, e.g. an unknown option symbol
+struct InvalidArgumentError <: PolarsError
+  inner::polars_error_t
+end
+# any other polars error kind, see `kind`
+struct OtherError <: PolarsError
+  inner::polars_error_t
+end
+
+kind(e::PolarsError)::Symbol = polars_error_kind(e.inner)
+message(e::PolarsError)::String = polars_error_message(e.inner)
+function_name(e::PolarsError)::String = polars_error_function(e.inner)
+Base.showerror(io::Base.IO, e::PolarsError) = print(io, nameof(typeof(e)), " in ", function_name(e), ": ", message(e))
+
+# the exception thrown for a polars_error_t, see FFI._rethrow
+function exception(err::polars_error_t)::PolarsError
+  sym = polars_error_kind(err)
+  if sym === :ColumnNotFound || sym === :SchemaFieldNotFound || sym === :StructFieldNotFound
+    return ColumnNotFoundError(err)
+  elseif sym === :SchemaMismatch
+    return SchemaMismatchError(err)
+  elseif sym === :ShapeMismatch
+    return ShapeMismatchError(err)
+  elseif sym === :ComputeError
+    return ComputeError(err)
+  elseif sym === :InvalidOperation
+    return InvalidOperationError(err)
+  elseif sym === :Duplicate
+    return DuplicateError(err)
+  elseif sym === :OutOfBounds
+    return OutOfBoundsError(err)
+  elseif sym === :NoData
+    return NoDataError(err)
+  elseif sym === :IO
+    return IOError(err)
+  elseif sym === :InvalidArgument
+    return InvalidArgumentError(err)
+  else
+    return OtherError(err)
+  end
+end
+
+end # module Errors
@@ -0,0 +1,91 @@
+"""
+Lazy expressions, evaluated against a frame by `select` and `with_columns`, e.g.
+`with_columns(df, cum_sum(col("x")) |> over("id"))`.
+"""
+module Exprs
+
+import ..FFI
+import ..Polars: Value, Scalar
+
+struct Expr
+  inner::FFI.polars_expr_t
+end
+
+Base.convert(::Type{Expr}, e::FFI.polars_expr_t) = Expr(e)
+Base.show(io::IO, e::Expr) = print(io, FFI.polars_expr_display(e.inner))
+
+col(name::AbstractString)::Expr = FFI.polars_expr_col(String(name))
+lit(x)::Expr = FFI.polars_expr_lit(Value(x).inner)
+# the elements of a list in `Lists.eval`
+element()::Expr = col("")
+alias(e::Expr, name::AbstractString)::Expr = FFI.polars_expr_alias(e.inner, String(name))
+
+# elementwise, comparisons only broadcast like for columns: col("x") .> 1
+# a number operand takes the dtype of the other side when it fits, col("x") + 1 keeps the dtype of x
+_lit(x)::Expr = FFI.polars_expr_lit_dyn(Value(x).inner)
+binary(a::Expr, b::Expr, op::Symbol)::Expr = FFI.polars_expr_binary(a.inner, b.inner, op)
+for (f, op) in ((:+, :add), (:-, :sub), (:*, :mul), (:/, :div), (:rem, :rem), (:&, :and), (:|, :or))
+  @eval Base.$f(a::Expr, b::Expr) = binary(a, b, $(QuoteNode(op)))
+  @eval Base.$f(a::Expr, b::Scalar) = binary(a, _lit(b), $(QuoteNode(op)))
+  @eval Base.$f(a::Scalar, b::Expr) = binary(_lit(a), b, $(QuoteNode(op)))
+end
+Base.:-(a::Expr)::Expr = FFI.polars_expr_neg(a.inner)
+for (f, op) in ((:+, :add), (:-, :sub), (:*, :mul), (:/, :div), (:rem, :rem), (:&, :and), (:|, :or),
+                (:(==), :eq), (:!=, :ne), (:<, :lt), (:<=, :le), (:>, :gt), (:>=, :ge))
+  @eval Base.broadcasted(::typeof($f), a::Expr, b::Expr) = binary(a, b, $(QuoteNode(op)))
+  @eval Base.broadcasted(::typeof($f), a::Expr, b::Scalar) = binary(a, _lit(b), $(QuoteNode(op)))
+  @eval Base.broadcasted(::typeof($f), a::Scalar, b::Expr) = binary(_lit(a), b, $(QuoteNode(op)))
+end
+
+# aggregations, one value for the frame or per group
+agg(e::Expr, op::Symbol)::Expr = FFI.polars_expr_agg(e.inner, op)
+Base.sum(e::Expr) = agg(e, :sum)
+Base.prod(e::Expr) = agg(e, :product)
+Base.minimum(e::Expr) = agg(e, :min)
+Base.maximum(e::Expr) = agg(e, :max)
+Base.first(e::Expr) = agg(e, :first)
+Base.last(e::Expr) = agg(e, :last)
+Base.count(e::Expr) = agg(e, :count)
+len(e::Expr) = agg(e, :len)
+mean(e::Expr) = agg(e, :mean)
+median(e::Expr) = agg(e, :median)
+n_unique(e::Expr) = agg(e, :n_unique)
+
+# running aggregates, from the last row if rev
+cum_sum(e::Expr; rev::Bool=false)::Expr = FFI.polars_expr_cum(e.inner, :sum, rev)
+cum_prod(e::Expr; rev::Bool=false)::Expr = FFI.polars_expr_cum(e.inner, :prod, rev)
+cum_min(e::Expr; rev::Bool=false)::Expr = FFI.polars_expr_cum(e.inner, :min, rev)
+cum_max(e::Expr; rev::Bool=false)::Expr = FFI.polars_expr_cum(e.inner, :max, rev)
+# running count of the non-null values
+cum_count(e::Expr; rev::Bool=false)::Expr = FFI.polars_expr_cum(e.inner, :count, rev)
+# method can be :average, :min, :max, :dense, :ordinal
+rank(e::Expr; method::Symbol=:average, rev::Bool=false)::Expr = FFI.polars_expr_rank(e.inner, method, rev)
+# values n rows later, the first n rows are null, negative n moves values up
+shift(e::Expr, n::Integer=1)::Expr = FFI.polars_expr_shift(e.inner, n)
+lag(e::Expr, n::Integer=1)::Expr = shift(e, n)
+lead(e::Expr, n::Integer=1)::Expr = shift(e, -n)
+Base.diff(e::Expr, n::Integer=1)::Expr = FFI.polars_expr_diff(e.inner, n)
+pct_change(e::Expr, n::Integer=1)::Expr = FFI.polars_expr_pct_change(e.inner, n)
+
+_expr(e::Expr) = e
+_expr(name::AbstractString) = col(name)
+
+"""
+    over(e, partition_by...; order_by=[], rev=false)
+    over(partition_by; order_by=[], rev=false)
+
+Evaluates `e` within each group of `partition_by`, visiting the rows in the order of
+`order_by` (descending if `rev`), and maps the result back to the rows of the group.
+Groups and orders are column names or expressions. The curried form for pipes takes one
+name or a tuple of keys, e.g. `cum_sum(col("x")) |> over((col("id"), "g"))`.
+"""
+function over(e::Expr, partition_by...; order_by=Union{Expr, String}[], rev::Bool=false)::Expr
+  isempty(partition_by) && throw(ArgumentError("over needs a partition key, the curried form takes expressions in a tuple"))
+  order_by = order_by isa Union{Expr, AbstractString} ? [order_by] : order_by
+  return FFI.polars_expr_over(e.inner, FFI.polars_expr_t[_expr(p).inner for p in partition_by], FFI.polars_expr_t[_expr(o).inner for o in order_by], rev)
+end
+# curried for pipes: cum_sum(col("x")) |> over("id")
+over(partition_by::AbstractString; kwargs...) = e -> over(e, partition_by; kwargs...)
+over(partition_by::Union{Tuple, AbstractVector}; kwargs...) = e -> over(e, partition_by...; kwargs...)
+
+end # module Exprs
@@ -1,7 +1,19 @@
 module FFI
 
 import Dates
-_jl_datetime(s, unit, time_zone=nothing) = Dates.DateTime(1970) + _jl_period(s, unit)
+import ..Timestamps: Timestamp
+# naive values on whole milliseconds stay a DateTime, any other value would lose information there
+_fits_datetime(s, unit, time_zone) = isnothing(time_zone) && (unit === :ms || (unit === :μs && s % 1000 == 0))
+_jl_datetime(s, unit, time_zone=nothing) = if _fits_datetime(s, unit, time_zone)
+  Dates.DateTime(1970) + Dates.Millisecond(unit === :ms ? s : s ÷ 1000)
+else
+  Timestamp(s, unit, time_zone)
+end
+# a column is either all DateTime or all Timestamp
+function _jl_datetime_vector(values, unit, time_zone)
+  fits = all(v -> isnothing(v) || _fits_datetime(v, unit, time_zone), values)
+  return [isnothing(v) ? nothing : fits ? _jl_datetime(v, unit, time_zone) : Timestamp(v, unit, time_zone) for v in values]
+end
 _jl_date(d) = Dates.Date(1970) + Dates.Day(d)
 _jl_time(t) = Dates.Time(0) + Dates.Nanosecond(t)
 _jl_period(t, unit) = if unit === :ms
@@ -12,6 +24,53 @@ elseif unit === :ns
   Dates.Nanosecond(t)
 end
 
+_jl_int128(hi, lo) = (Int128(hi) << 64) | Int128(lo)
+
+import ..Decimals: Decimal
+_jl_decimal(hi, lo, scale) = Decimal(_jl_int128(hi, lo), scale)
+_jl_decimal_vector(values, scale) = [isnothing(v) ? nothing : Decimal(v, scale) for v in values]
+
+# bulk conversion: copies n values of eltype from ptr, valid is C_NULL or points to n Bools
+function _jl_vector(eltype::Symbol, ptr::Ptr{UInt8}, valid::Ptr{UInt8}, n)
+  T = getfield(Base, eltype)
+  values = copy(unsafe_wrap(Base.Array, Ptr{T}(ptr), n))
+  valid == C_NULL && return values
+  mask = unsafe_wrap(Base.Array, Ptr{Bool}(valid), n)
+  return Union{Nothing, T}[mask[i] ? values[i] : nothing for i in 1:n]
+end
+# offsets are 0-based with length(valid) + 1 entries, as in arrow
+_jl_list_vector(values, offsets, valid) = [
+  (isnothing(valid) || valid[i]) ? values[offsets[i]+1:offsets[i+1]] : nothing
+  for i in 1:length(offsets)-1
+]
+# strings are concatenated in data and sliced by offsets as in _jl_list_vector
+_jl_string_vector(data, offsets, valid) = [
+  (isnothing(valid) || valid[i]) ? String(data[offsets[i]+1:offsets[i+1]]) : nothing
+  for i in 1:length(offsets)-1
+]
+# refs are 1-based into pool and 0 marks a null, as in CategoricalArrays
+_jl_categorical_parts(refs, pool) = (refs, pool)
+_jl_categorical_vector(refs, pool) = [r == 0 ? nothing : pool[r] for r in refs]
+function _jl_struct(v)
+  n = polars_value_struct_len(v)
+  names = Tuple(polars_value_struct_field_name(v, i - 1) for i in 1:n)
+  return NamedTuple{names}(Tuple(polars_value_extract(polars_value_struct_field(v, i - 1)) for i in 1:n))
+end
+function _jl_struct_vector(col, valid)
+  fields = [polars_column_struct_field(col, i - 1) for i in 1:polars_column_struct_width(col)]
+  names = Tuple(Symbol(polars_column_name(f)) for f in fields)
+  values = [polars_column_to_vector(f) for f in fields]
+  return [
+    (isnothing(valid) || valid[i]) ? NamedTuple{names}(Tuple(v[i] for v in values)) : nothing
+    for i in 1:polars_column_len(col)
+  ]
+end
+# fallback for dtypes without a bulk conversion
+_jl_column_vector(col) = [polars_value_extract(polars_column_get(col, i - 1)) for i in 1:polars_column_len(col)]
+
+# the library functions are wrapped in Raw and re-exported below, so that a failed call throws its typed error
+module Raw
+
 using JlrsCore.Wrap
 # using libpolars_jll
 # export libpolars_jll
@@ -36,4 +95,29 @@ function __init__()
   @initjlrs
 end
 
+end # module Raw
+
+# typed errors arrive as a JlrsError, with the polars_error_t they came from stashed
+# on the rust side, which is thrown as the matching Polars.Errors exception
+function _rethrow()
+  err = Raw.polars_error_take_last()
+  isnothing(err) && rethrow()
+  throw(parentmodule(@__MODULE__).Errors.exception(err))
+end
+
+for name in names(Raw; all=true)
+  startswith(string(name), "polars_") || continue
+  f = getfield(Raw, name)
+  if f isa Base.Type
+    @eval const $name = Raw.$name
+  elseif name !== :polars_error_take_last
+    # one wrapper per method with the same argument types, so that calls stay specialized
+    for m in methods(f)
+      types = m.sig isa Base.DataType ? fieldtypes(m.sig)[2:end] : ntuple(_ -> Any, m.nargs - 1)
+      args = [Symbol(:x, i) for i in eachindex(types)]
+      @eval $name($((:($a::$T) for (a, T) in zip(args, types))...)) = try Raw.$name($(args...)) catch; _rethrow() end
+    end
+  end
+end
+
 end # module FFI
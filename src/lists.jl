@@ -0,0 +1,54 @@
+"""
+Kernels of the polars `list` namespace on List and Array columns, e.g. `Lists.sum(col)`.
+Array columns are treated as List columns, so nested results are List columns.
+"""
+module Lists
+
+import ..FFI
+import ..Polars: Column, Value, list_values
+import ..Exprs: Expr
+
+lengths(col::Column)::Column = FFI.polars_column_list_len(col.inner)
+# element i of every list, negative indices count from the end
+function get(col::Column, i::Integer; null_on_oob::Bool=false)::Column
+  i == 0 && throw(ArgumentError("index is 1-based, got 0"))
+  return FFI.polars_column_list_get(col.inner, i > 0 ? i - 1 : i, null_on_oob)
+end
+# length elements from the 1-based start, negative starts count from the end, nothing takes the rest
+function slice(col::Column, start::Integer, length::Union{Integer, Nothing}=nothing)::Column
+  start == 0 && throw(ArgumentError("start is 1-based, got 0"))
+  return FFI.polars_column_list_slice(col.inner, start > 0 ? start - 1 : start, isnothing(length) ? nothing : UInt64(length))
+end
+contains(col::Column, x)::Column = FFI.polars_column_list_contains(col.inner, Value(x).inner)
+
+# one value per list
+for (f, op) in ((:sum, :sum), (:minimum, :min), (:maximum, :max), (:n_unique, :n_unique), (:first, :first), (:last, :last))
+  @eval $f(col::Column)::Column = FFI.polars_column_list_reduce(col.inner, $(QuoteNode(op)))
+end
+mean(col::Column)::Column = FFI.polars_column_list_reduce(col.inner, :mean)
+median(col::Column)::Column = FFI.polars_column_list_reduce(col.inner, :median)
+
+sort(col::Column; rev::Bool=false, nulls_last::Bool=false)::Column = FFI.polars_column_list_sort(col.inner, rev, nulls_last)
+unique(col::Column; maintain_order::Bool=false)::Column = FFI.polars_column_list_unique(col.inner, maintain_order)
+# lists of strings joined with sep in between
+join(col::Column, sep::AbstractString; ignore_nulls::Bool=false)::Column = FFI.polars_column_list_join(col.inner, String(sep), ignore_nulls)
+
+"""
+    eval(col::Column, e::Exprs.Expr)::Column
+
+Evaluates `e` on every list separately, like `list.eval` in polars. `Exprs.element()` refers to
+the elements of the list, e.g. `eval(col, rank(element()))` ranks within each list.
+"""
+eval(col::Column, e::Expr)::Column = FFI.polars_column_list_eval(col.inner, e.inner)
+
+"""
+    map_values(f, col::Column)::Column
+
+Applies `f` to the elements of all lists at once. `f` gets the flattened values as a Column and
+must return a Column of the same length, e.g. `map_values(v -> v * 2, col)`. Unlike `eval`, `f`
+sees every list together, so only elementwise kernels keep their meaning per list. The lists keep
+their lengths and nulls.
+"""
+map_values(f, col::Column)::Column = FFI.polars_column_list_with_values(col.inner, f(list_values(col)).inner)
+
+end # module Lists
@@ -0,0 +1,34 @@
+"""
+SQL queries over frames registered under table names, e.g.
+`execute(SQLContext("t" => df), "SELECT k, SUM(v) AS v FROM t GROUP BY k")`.
+"""
+module SQL
+
+import ..FFI
+import ..Polars: DataFrame, LazyFrame
+
+struct SQLContext
+  inner::FFI.polars_sql_context_t
+end
+
+SQLContext()::SQLContext = SQLContext(FFI.polars_sql_context_new())
+# a context with every name => frame pair registered
+function SQLContext(tables::Pair{<:AbstractString, <:Union{DataFrame, LazyFrame}}...)::SQLContext
+  ctx = SQLContext()
+  for (name, df) in tables
+    register(ctx, name, df)
+  end
+  return ctx
+end
+Base.show(io::IO, ctx::SQLContext) = print(io, "SQLContext(", join(tables(ctx), ", "), ")")
+
+# registers df as table name, replacing a table of the same name
+register(ctx::SQLContext, name::AbstractString, df::DataFrame)::Nothing = FFI.polars_sql_context_register(ctx.inner, String(name), df.inner)
+# a lazy table runs as part of every query using it
+register(ctx::SQLContext, name::AbstractString, lf::LazyFrame)::Nothing = FFI.polars_sql_context_register_lazy(ctx.inner, String(name), lf.inner)
+unregister(ctx::SQLContext, name::AbstractString)::Nothing = FFI.polars_sql_context_unregister(ctx.inner, String(name))
+# the names of the registered tables, sorted
+tables(ctx::SQLContext)::Vector{String} = FFI.polars_sql_context_tables(ctx.inner)
+execute(ctx::SQLContext, query::AbstractString)::DataFrame = FFI.polars_sql_context_execute(ctx.inner, String(query))
+
+end # module SQL
@@ -0,0 +1,53 @@
+"""
+Kernels of the polars `str` namespace on String columns, e.g. `Strings.contains(col, r"^a")`.
+They run in polars and return new columns with the same name.
+"""
+module Strings
+
+import ..FFI
+import ..Polars: Column, DataType, intoraw
+
+# polars takes the flags of a Regex inline, r"a"i is sent as "(?i)a"
+const _FLAGS = ((Base.PCRE.CASELESS, 'i'), (Base.PCRE.MULTILINE, 'm'), (Base.PCRE.DOTALL, 's'), (Base.PCRE.EXTENDED, 'x'))
+function _pattern(r::Regex)::String
+  flags = String([c for (opt, c) in _FLAGS if r.compile_options & opt != 0])
+  return isempty(flags) ? r.pattern : "(?$flags)" * r.pattern
+end
+
+contains(col::Column, pattern::AbstractString)::Column = FFI.polars_column_str_contains(col.inner, String(pattern), true)
+contains(col::Column, pattern::Regex)::Column = FFI.polars_column_str_contains(col.inner, _pattern(pattern), false)
+starts_with(col::Column, prefix::AbstractString)::Column = FFI.polars_column_str_starts_with(col.inner, String(prefix))
+ends_with(col::Column, suffix::AbstractString)::Column = FFI.polars_column_str_ends_with(col.inner, String(suffix))
+# with a Regex, value can refer to capture groups as $1 or ${name}
+replace(col::Column, (pattern, value)::Pair{<:AbstractString, <:AbstractString})::Column = FFI.polars_column_str_replace(col.inner, String(pattern), String(value), true, false)
+replace(col::Column, (pattern, value)::Pair{Regex, <:AbstractString})::Column = FFI.polars_column_str_replace(col.inner, _pattern(pattern), String(value), false, false)
+replace_all(col::Column, (pattern, value)::Pair{<:AbstractString, <:AbstractString})::Column = FFI.polars_column_str_replace(col.inner, String(pattern), String(value), true, true)
+replace_all(col::Column, (pattern, value)::Pair{Regex, <:AbstractString})::Column = FFI.polars_column_str_replace(col.inner, _pattern(pattern), String(value), false, true)
+# List column of the parts, inclusive keeps the separator at the end of each part
+split(col::Column, by::AbstractString; inclusive::Bool=false)::Column = FFI.polars_column_str_split(col.inner, String(by), inclusive)
+# capture group `group` of the first match, 0 is the whole match
+extract(col::Column, pattern::Regex, group::Integer=1)::Column = FFI.polars_column_str_extract(col.inner, _pattern(pattern), convert(UInt, group))
+# List column of all matches
+extract_all(col::Column, pattern::Regex)::Column = FFI.polars_column_str_extract_all(col.inner, _pattern(pattern))
+# Struct column with a field per capture group, unnamed groups are called "1", "2", ...
+extract_groups(col::Column, pattern::Regex)::Column = FFI.polars_column_str_extract_groups(col.inner, _pattern(pattern))
+to_lowercase(col::Column)::Column = FFI.polars_column_str_to_lowercase(col.inner)
+to_uppercase(col::Column)::Column = FFI.polars_column_str_to_uppercase(col.inner)
+# strips any of chars, whitespace if nothing, side can be :both, :start, :end
+strip_chars(col::Column, chars::Union{AbstractString, Nothing}=nothing; side::Symbol=:both)::Column = FFI.polars_column_str_strip_chars(col.inner, isnothing(chars) ? nothing : String(chars), side)
+len_bytes(col::Column)::Column = FFI.polars_column_str_len_bytes(col.inner)
+len_chars(col::Column)::Column = FFI.polars_column_str_len_chars(col.inner)
+# length characters from the 1-based start, negative starts count from the end, nothing takes the rest
+function slice(col::Column, start::Integer, length::Union{Integer, Nothing}=nothing)::Column
+  start == 0 && throw(ArgumentError("start is 1-based, got 0"))
+  return FFI.polars_column_str_slice(col.inner, start > 0 ? start - 1 : start, isnothing(length) ? nothing : UInt64(length))
+end
+zfill(col::Column, length::Integer)::Column = FFI.polars_column_str_zfill(col.inner, convert(UInt, length))
+# dtype can be Date, DateTime or Time, format uses chrono specifiers (e.g. "%Y-%m-%d") and is inferred if nothing
+strptime(col::Column, dtype::DataType, format::Union{AbstractString, Nothing}=nothing; strict::Bool=true)::Column =
+  FFI.polars_column_str_strptime(col.inner, intoraw(dtype), isnothing(format) ? nothing : String(format), strict)
+# dtype is inferred from the values if nothing
+json_decode(col::Column, dtype::Union{DataType, Nothing}=nothing)::Column =
+  FFI.polars_column_str_json_decode(col.inner, isnothing(dtype) ? nothing : intoraw(dtype))
+
+end # module Strings
@@ -0,0 +1,46 @@
+"""
+Kernels of the polars `dt` namespace on Date, DateTime, Time and Duration columns,
+e.g. `Temporal.truncate(col, "15m")`. They run in polars and return new columns with the same name.
+"""
+module Temporal
+
+import ..FFI
+import ..Polars: Column, DataTypes, cast, convert_time_zone, replace_time_zone, cast_time_unit
+
+const COMPONENTS = (:millennium, :century, :year, :iso_year, :quarter, :month, :week, :weekday, :day,
+                    :ordinal_day, :hour, :minute, :second, :millisecond, :microsecond, :nanosecond, :is_leap_year)
+# year(col), month(col), ..., weekday is 1 for monday, second excludes the fraction
+for component in COMPONENTS
+  @eval $component(col::Column)::Column = FFI.polars_column_dt_component(col.inner, $(QuoteNode(component)))
+end
+# the Date or Time part of a DateTime, in the local time of zoned values
+date(col::Column)::Column = FFI.polars_column_dt_component(col.inner, :date)
+time(col::Column)::Column = FFI.polars_column_dt_component(col.inner, :time)
+
+# every and by are interval strings like "1h30m", units are ns, us, ms, s, m, h, d, w, mo, q, y
+truncate(col::Column, every::AbstractString)::Column = FFI.polars_column_dt_truncate(col.inner, String(every))
+Base.round(col::Column, every::AbstractString)::Column = FFI.polars_column_dt_round(col.inner, String(every))
+offset_by(col::Column, by::AbstractString)::Column = FFI.polars_column_dt_offset_by(col.inner, String(by))
+
+# integers since the unix epoch, unit can be :ns, :μs, :ms, :s, :d
+epoch(col::Column, unit::Symbol=:s)::Column = FFI.polars_column_dt_epoch(col.inner, unit)
+# the inverse of epoch for integer columns
+function from_epoch(col::Column, unit::Symbol=:s)::Column
+  if unit === :d
+    return cast(col, DataTypes.Date())
+  elseif unit === :s
+    return cast(col * 1000, DataTypes.DateTime{:ms}(nothing))
+  end
+  return cast(col, DataTypes.DateTime{unit}(nothing))
+end
+
+# format uses chrono specifiers, e.g. "%Y-%m-%d %H:%M"
+strftime(col::Column, format::AbstractString)::Column = FFI.polars_column_dt_strftime(col.inner, String(format))
+
+# whole units in a Duration, unit can be :days, :hours, :minutes, :seconds, :milliseconds, :microseconds, :nanoseconds
+total(col::Column, unit::Symbol)::Column = FFI.polars_column_dt_total(col.inner, unit)
+for unit in (:days, :hours, :minutes, :seconds, :milliseconds, :microseconds, :nanoseconds)
+  @eval $(Symbol(:total_, unit))(col::Column)::Column = total(col, $(QuoteNode(unit)))
+end
+
+end # module Temporal
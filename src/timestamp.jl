@@ -0,0 +1,44 @@
+module Timestamps
+
+import Dates
+
+"""
+    Timestamp(value::Int64, unit::Symbol, time_zone::Union{String, Nothing})
+
+Instant `value` units after the unix epoch in UTC, the same representation polars uses for
+a Datetime. `unit` can be `:ns`, `:μs` or `:ms`, `time_zone` is the zone the instant belongs
+to, or `nothing` for a naive datetime.
+"""
+struct Timestamp
+  value::Int64
+  unit::Symbol
+  time_zone::Union{String, Nothing}
+end
+
+const UNITS_PER_SECOND = (ns=10^9, μs=10^6, ms=10^3)
+
+Timestamp(dt::Dates.DateTime, time_zone=nothing) = Timestamp(Dates.value(dt - Dates.DateTime(1970)), :ms, time_zone)
+
+# nanoseconds after the epoch, wide enough for every unit
+nanoseconds(ts::Timestamp)::Int128 = Int128(ts.value) * (10^9 ÷ UNITS_PER_SECOND[ts.unit])
+
+"""
+    Dates.DateTime(ts::Timestamp)
+
+UTC time of `ts`, truncated to milliseconds.
+"""
+Dates.DateTime(ts::Timestamp) = Dates.DateTime(1970) + Dates.Millisecond(fld(nanoseconds(ts), 10^6))
+
+Base.:(==)(a::Timestamp, b::Timestamp) = nanoseconds(a) == nanoseconds(b) && a.time_zone == b.time_zone
+Base.hash(ts::Timestamp, h::UInt) = hash(nanoseconds(ts), hash(ts.time_zone, hash(Timestamp, h)))
+Base.isless(a::Timestamp, b::Timestamp) = nanoseconds(a) < nanoseconds(b)
+
+function Base.show(io::IO, ts::Timestamp)
+  units = UNITS_PER_SECOND[ts.unit]
+  seconds, fraction = fldmod(ts.value, units)
+  print(io, Dates.format(Dates.unix2datetime(seconds), "yyyy-mm-ddTHH:MM:SS"))
+  print(io, ".", lpad(string(fraction), ndigits(units) - 1, '0'))
+  isnothing(ts.time_zone) || print(io, " ", ts.time_zone)
+end
+
+end # module Timestamps
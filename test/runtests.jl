@@ -1,5 +1,5 @@
 # rm -rf ~/.julia/compiled/v1.11/Polars && cargo build && julia --project -e 'using Test; include("test/runtests.jl")'
-using Polars, Test, JlrsCore, Dates
+using Polars, Test, JlrsCore, Dates, Statistics
 
 @testset "Basic tests" begin
   println("Polars version: ", Polars.version())
@@ -40,12 +40,19 @@ end
   test_col("col_float32", Polars.DataTypes.Float32)
   test_col("col_float64", Polars.DataTypes.Float64)
   test_col("col_decimal", Polars.DataTypes.Decimal{9, 3})
+  test_col("col_string", Polars.DataTypes.String)
+  test_col("col_binary", Polars.DataTypes.Binary)
   test_col("col_datetime", Polars.DataTypes.DateTime{:μs})
+  test_col("col_datetime_ns", Polars.DataTypes.DateTime{:ns})
+  test_col("col_datetime_tz", Polars.DataTypes.DateTime{:ms})
   test_col("col_date", Polars.DataTypes.Date)
   test_col("col_time", Polars.DataTypes.Time{:μs})
   test_col("col_duration", Polars.DataTypes.Duration{:μs})
   test_col("col_list_int32", Polars.DataTypes.List{Polars.DataTypes.Int32})
   test_col("col_array_float64", Polars.DataTypes.Array{Polars.DataTypes.Float64, 1})
+  test_col("col_struct", Polars.DataTypes.Struct{@NamedTuple{a::Polars.DataTypes.Int64, b::Polars.DataTypes.Float64}})
+  test_col("col_categorical", Polars.DataTypes.Categorical)
+  test_col("col_enum", Polars.DataTypes.Enum)
 end
 
 @testset "Column tests" begin
@@ -67,7 +74,62 @@ end
   @test Polars.name(col2) == "mycol2"
   dtype = Polars.dtype(col2)
   @test typeof(dtype) == Polars.DataTypes.Float32
-  @test_throws JlrsCore.JlrsError Polars.get_column(df, "nonexistent")
+  @test_throws Polars.Errors.ColumnNotFoundError Polars.get_column(df, "nonexistent")
+end
+
+@testset "Error tests" begin
+  df = Polars.read_parquet("test.parquet")
+  # these used to abort the julia session
+  @test_throws Polars.Errors.DuplicateError Polars.DataFrame([Polars.Column("a"), Polars.Column("a")])
+  @test_throws Polars.Errors.ShapeMismatchError Polars.DataFrame([df["col_int8"], Polars.Column("a")])
+  @test_throws Polars.Errors.IOError Polars.read_parquet("nonexistent.parquet")
+  err = try
+    Polars.get_column(df, "nonexistent")
+  catch e
+    e
+  end
+  @test err isa Polars.PolarsError
+  @test Polars.Errors.kind(err) === :ColumnNotFound
+  @test Polars.Errors.function_name(err) == "polars_dataframe_get_column"
+  @test occursin("nonexistent", Polars.Errors.message(err))
+  # an error nobody took is dropped by the next call
+  try Polars.FFI.Raw.polars_dataframe_get_column(df.inner, "nonexistent") catch end
+  Polars.height(df)
+  @test isnothing(Polars.FFI.Raw.polars_error_take_last())
+end
+
+@testset "Concat tests" begin
+  df = Polars.read_parquet("test.parquet")
+  @test Polars.height(Polars.concat([df, df])) == 6
+  @test Polars.height(Polars.concat([df, df, df]; how=:vertical_relaxed, rechunk=true)) == 9
+  a = Polars.DataFrame([Polars.Column("a")])
+  b = Polars.DataFrame([Polars.Column("b", dtype=Polars.DataTypes.Float64())])
+  df2 = Polars.concat([a, b]; how=:horizontal)
+  @test Polars.name(df2["b"]) == "b"
+  df3 = Polars.concat([a, b]; how=:diagonal)
+  @test typeof(Polars.dtype(df3["a"])) == Polars.DataTypes.Int64
+  @test typeof(Polars.dtype(df3["b"])) == Polars.DataTypes.Float64
+  @test_throws Polars.PolarsError Polars.concat([a, b])
+  @test_throws Polars.Errors.InvalidArgumentError Polars.concat([a, b]; how=:sideways)
+  i32 = Polars.DataFrame([Polars.Column("k", ["a", "b"]), Polars.Column("v", Int32[1, 2])])
+  i64 = Polars.DataFrame([Polars.Column("k", ["c"]), Polars.Column("v", [3])])
+  extra = Polars.DataFrame([Polars.Column("k", ["d"]), Polars.Column("w", [1.5])])
+  @test_throws Polars.PolarsError Polars.concat([i32, i64])
+  df4 = Polars.concat([i32, i64]; how=:vertical_relaxed)
+  @test typeof(Polars.dtype(df4["v"])) == Polars.DataTypes.Int64
+  @test collect(df4["v"]) == [1, 2, 3]
+  df5 = Polars.concat([i64, extra]; how=:diagonal)
+  @test collect(df5["k"]) == ["c", "d"]
+  @test isequal(collect(df5["v"]), [3, nothing])
+  @test isequal(collect(df5["w"]), [nothing, 1.5])
+  @test_throws Polars.PolarsError Polars.concat([i32, i64, extra]; how=:diagonal)
+  df6 = Polars.concat([i32, i64, extra]; how=:diagonal_relaxed)
+  @test typeof(Polars.dtype(df6["v"])) == Polars.DataTypes.Int64
+  @test isequal(collect(df6["v"]), [1, 2, 3, nothing])
+  @test isequal(collect(df6["w"]), [nothing, nothing, nothing, 1.5])
+  df7 = Polars.concat([i64, Polars.DataFrame([Polars.Column("w", [2.5])])]; how=:horizontal)
+  @test collect(df7["v"]) == [3]
+  @test collect(df7["w"]) == [2.5]
 end
 
 @testset "DataType tests" begin
@@ -86,13 +148,700 @@ end
   @test df[["col_int8", "col_int16", "col_int32", "col_int64"], 1:3] == [[1, 2, 3], [1, 2, 3], [1, 2, 3], [1, 2, 3]]
   @test df[["col_uint8", "col_uint16", "col_uint32", "col_uint64"], 1:3] == [[1, 2, 3], [1, 2, 3], [1, 2, 3], [1, 2, 3]]
   @test df[["col_float32", "col_float64"], 1:3] == [[1.0, 2.0, 3.0], [1.0, 2.0, 3.0]]
-  # @test df["col_decimal", 1:3] == [Decimal(1, 0), Decimal(2, 0), Decimal(3, 0)]
+  @test df["col_decimal", 1:3] == [Polars.Decimal(1, 0), Polars.Decimal(2, 0), Polars.Decimal(3, 0)]
   @test df["col_string", 1:3] == ["a", "b", "c"]
   @test df["col_datetime", 1:3] == [DateTime(2023, 1, 1), DateTime(2023, 1, 2), DateTime(2023, 1, 3)]
   @test df["col_date", 1:3] == [Date(2023, 1, 1), Date(2023, 1, 2), Date(2023, 1, 3)]
   @test df["col_time", 1:3] == [Time(12, 0), Time(13, 0), Time(14, 0)]
   @test df["col_duration", 1:3] == [Microsecond(1000), Microsecond(2000), Microsecond(3000)]
-  @test_throws JlrsCore.JlrsError df["col_null", 4]
+  @test_throws Polars.Errors.OutOfBoundsError df["col_null", 4]
+end
+
+@testset "Decimal tests" begin
+  df = Polars.read_parquet("test.parquet")
+  @test df["col_decimal", 1] === Polars.Decimal(1000, 3)
+  @test collect(df["col_decimal"]) == [Polars.Decimal(1), Polars.Decimal(2), Polars.Decimal(3)]
+  @test string(parse(Polars.Decimal, "-12.050")) == "-12.050"
+  @test Polars.Decimal(150, 2) == Polars.Decimal(15, 1)
+  @test Polars.Decimal(0, 3) == Polars.Decimal(0)
+  e20 = Int128(10)^20
+  # e20 * e20 wraps around in Int128, a rescaled comparison would see the same value
+  @test Polars.Decimal(e20, 0) != Polars.Decimal(e20 * e20, 20)
+  values = [parse(Polars.Decimal, "0.1"), nothing, parse(Polars.Decimal, "12345678901234567890.25")]
+  col = Polars.Column("price", values)
+  @test Polars.dtype(col) == Polars.DataTypes.Decimal(38, 2)
+  @test Polars.null_count(col) == 1
+  @test col[3] === Polars.Decimal(1234567890123456789025, 2)
+  @test collect(col) == values
+  int64 = Polars.DataTypes.intoraw(Polars.DataTypes.Int64())
+  @test_throws Polars.Errors.ShapeMismatchError Polars.FFI.polars_column_from_bits("x", int64, zeros(UInt8, 12), Bool[])
+  @test_throws Polars.Errors.ShapeMismatchError Polars.FFI.polars_column_from_bits("x", int64, zeros(UInt8, 16), [true])
+  @test_throws Polars.Errors.ShapeMismatchError Polars.FFI.polars_column_from_bits("x", int64, zeros(UInt8, 16), [true, true, true])
+end
+
+@testset "Datetime tests" begin
+  df = Polars.read_parquet("test.parquet")
+  @test df["col_datetime_ns", 1] == Polars.Timestamp(1, :ns, nothing)
+  @test Polars.Timestamps.nanoseconds.(collect(df["col_datetime_ns"])) == [1, 2, 3]
+  @test string(df["col_datetime_ns", 3]) == "1970-01-01T00:00:00.000000003"
+  @test collect(df["col_datetime"]) == [DateTime(2023, 1, 1), DateTime(2023, 1, 2), DateTime(2023, 1, 3)]
+  ts = df["col_datetime_tz", 1]
+  @test ts.time_zone == "Asia/Shanghai"
+  @test DateTime(ts) == DateTime(2022, 12, 31, 16)
+  @test Polars.dtype(df["col_datetime_tz"]) == Polars.DataTypes.DateTime{:ms}("Asia/Shanghai")
+  utc = Polars.convert_time_zone(df["col_datetime_tz"], "UTC")
+  @test utc[1] == Polars.Timestamp(DateTime(2022, 12, 31, 16), "UTC")
+  naive = Polars.replace_time_zone(df["col_datetime_tz"], nothing)
+  @test naive[1] == DateTime(2023, 1, 1)
+  @test_throws Polars.Errors.InvalidOperationError Polars.convert_time_zone(df["col_datetime"], "UTC")
+  ns = Polars.cast_time_unit(df["col_datetime"], :ns)
+  @test Polars.dtype(ns) == Polars.DataTypes.DateTime{:ns}(nothing)
+  @test DateTime(ns[1]) == DateTime(2023, 1, 1)
+  us = Polars.Column("t", [Polars.Timestamp(1_500, :μs, nothing), Polars.Timestamp(2_000, :μs, nothing)])
+  @test Polars.dtype(us) == Polars.DataTypes.DateTime{:μs}(nothing)
+  @test us[1] === Polars.Timestamp(1_500, :μs, nothing)
+  @test us[2] == DateTime(1970, 1, 1, 0, 0, 0, 2)
+  @test collect(us) == [Polars.Timestamp(1_500, :μs, nothing), Polars.Timestamp(2_000, :μs, nothing)]
+  @test collect(Polars.cast_time_unit(Polars.Column("t", [DateTime(2024, 1, 1)]), :μs)) == [DateTime(2024, 1, 1)]
+end
+
+@testset "Bulk conversion tests" begin
+  df = Polars.read_parquet("test.parquet")
+  @test collect(df["col_int32"]) == Int32[1, 2, 3]
+  @test collect(df["col_float64"]) isa Vector{Float64}
+  @test collect(df["col_bool"]) == [true, false, true]
+  col = Polars.Column("x", [1, missing, 3])
+  @test collect(col) == [1, nothing, 3]
+  @test collect(Polars.Column("y", Float32[1.5, 2.5])) == Float32[1.5, 2.5]
+end
+
+@testset "Binary and Int128 tests" begin
+  df = Polars.read_parquet("test.parquet")
+  @test df["col_binary", 1] == b"a"
+  @test df["col_binary", 1] isa Vector{UInt8}
+  @test collect(df["col_binary"]) == [b"a", b"b", b"c"]
+  big = Int128(typemax(Int64)) + 1
+  col = Polars.Column("x", Int128[big, -big, 0])
+  @test Polars.dtype(col) == Polars.DataTypes.Int128()
+  @test col[1] === big
+  @test col[2] === -big
+  @test collect(col) == Int128[big, -big, 0]
+  col = Polars.Column("b", [UInt8[1, 2], nothing, UInt8[]])
+  @test Polars.dtype(col) == Polars.DataTypes.Binary()
+  @test isequal(collect(col), [UInt8[1, 2], nothing, UInt8[]])
+  col = Polars.Column("s", ["a", missing, "ü"])
+  @test Polars.dtype(col) == Polars.DataTypes.String()
+  @test isequal(collect(col), ["a", nothing, "ü"])
+  binary = Polars.DataTypes.intoraw(Polars.DataTypes.Binary())
+  @test_throws Polars.Errors.ShapeMismatchError Polars.FFI.polars_column_from_bytes("b", binary, UInt8[1, 2, 3], [1, 3], Bool[])
+  @test_throws Polars.Errors.ShapeMismatchError Polars.FFI.polars_column_from_bytes("b", binary, UInt8[1, 2, 3], [0, 2, 1, 3], Bool[])
+  @test_throws Polars.Errors.OutOfBoundsError Polars.FFI.polars_column_from_bytes("b", binary, UInt8[1, 2, 3], [0, 2, 4], Bool[])
+  @test_throws Polars.Errors.ShapeMismatchError Polars.FFI.polars_column_from_bytes("b", binary, UInt8[1, 2, 3], [0, 2, 3], [true])
+end
+
+@testset "List tests" begin
+  df = Polars.read_parquet("test.parquet")
+  @test df["col_list_int32", 1] == Int32[1, 2]
+  @test df["col_list_int32", 3] isa Vector{Int32}
+  @test df["col_array_float64", 1:3] == [[1.0], [3.0], [5.0]]
+  @test collect(df["col_list_int32"]) == [Int32[1, 2], Int32[3, 4], Int32[5]]
+  @test collect(df["col_array_float64"]) == [[1.0], [3.0], [5.0]]
+  col = df["col_list_int32"]
+  @test Polars.list_offsets(col) == [0, 2, 4, 5]
+  @test collect(Polars.list_values(col)) == Int32[1, 2, 3, 4, 5]
+  @test Polars.list_offsets(df["col_array_float64"]) == [0, 1, 2, 3]
+  @test collect(df["col_string"]) == ["a", "b", "c"]
+  values = [[1, nothing], nothing, Int[]]
+  col = Polars.Column("l", values)
+  @test Polars.dtype(col) == Polars.DataTypes.List(Polars.DataTypes.Int64())
+  @test Polars.null_count(col) == 1
+  @test Polars.list_offsets(col) == [0, 2, 2, 2]
+  @test isequal(collect(col), values)
+  @test isequal(col[1], [1, nothing])
+  @test col[2] === nothing
+  @test isempty(col[3])
+  flat = Polars.Column("v", [1, 2, 3]).inner
+  @test_throws Polars.Errors.ShapeMismatchError Polars.FFI.polars_column_from_list("l", flat, [1, 3], Bool[])
+  @test_throws Polars.Errors.ShapeMismatchError Polars.FFI.polars_column_from_list("l", flat, [0, 2, 1, 3], Bool[])
+  @test_throws Polars.Errors.OutOfBoundsError Polars.FFI.polars_column_from_list("l", flat, [0, 2, 4], Bool[])
+  @test_throws Polars.Errors.ShapeMismatchError Polars.FFI.polars_column_from_list("l", flat, [0, 2, 3], [true])
+end
+
+@testset "Struct tests" begin
+  df = Polars.read_parquet("test.parquet")
+  @test df["col_struct", 1] == (a=1, b=1.5)
+  @test df["col_struct", 2] == (a=2, b=nothing)
+  @test df["col_struct", 3] === nothing
+  @test isequal(collect(df["col_struct"]), [(a=1, b=1.5), (a=2, b=nothing), nothing])
+  dtype = Polars.DataTypes.Struct((a=Polars.DataTypes.Int32(), b=Polars.DataTypes.List(Polars.DataTypes.Float64())))
+  col = Polars.Column("s", dtype=dtype)
+  @test Polars.dtype(col) == dtype
+end
+
+@testset "Categorical tests" begin
+  df = Polars.read_parquet("test.parquet")
+  @test df["col_categorical", 1:3] == ["x", nothing, "x"]
+  @test df["col_enum", 1:3] == ["lo", "hi", nothing]
+  @test isequal(collect(df["col_enum"]), ["lo", "hi", nothing])
+  dtype = Polars.dtype(df["col_enum"])
+  @test Polars.DataTypes.categories(dtype) == ["lo", "mid", "hi"]
+  @test Polars.DataTypes.ordering(dtype) === :physical
+  @test Polars.DataTypes.categories(Polars.dtype(df["col_categorical"])) == ["x"]
+  other = Polars.cast(Polars.Column("c", ["only_here", "x"]), Polars.DataTypes.Categorical())
+  @test Polars.DataTypes.categories(Polars.dtype(other)) == ["only_here", "x"]
+  @test Polars.DataTypes.categories(Polars.dtype(df["col_categorical"])) == ["x"]
+  @test Polars.categorical_parts(df["col_enum"]) == (UInt32[1, 3, 0], ["lo", "mid", "hi"])
+  @test Polars.categorical_parts(df["col_categorical"]) == (UInt32[1, 0, 1], ["x"])
+  col = Polars.Column("e", dtype=Polars.DataTypes.Enum(["b", "a"]))
+  @test Polars.dtype(col) == Polars.DataTypes.Enum(["b", "a"])
+end
+
+@testset "Row tests" begin
+  df = Polars.DataFrame([Polars.Column("a", [1, 2, 3]), Polars.Column("b", ["x", missing, "z"])])
+  @test Polars.row(df, 1) == (a=1, b="x")
+  @test Polars.row(df, 2) === (a=2, b=nothing)
+  @test_throws Polars.Errors.OutOfBoundsError Polars.row(df, 4)
+  @test isequal(collect(Polars.rows(df; batch=2)), [(a=1, b="x"), (a=2, b=nothing), (a=3, b="z")])
+  @test length(Polars.rows(df)) == 3
+  @test [r.a for r in Polars.rows(df; batch=1)] == [1, 2, 3]
+  @test_throws ArgumentError Polars.rows(df; batch=0)
+  @test_throws ArgumentError Polars.rows(df; batch=-1)
+  @test Polars.row(df; by_predicate=Polars.Column("m", [false, false, true])) == (a=3, b="z")
+  @test_throws Polars.Errors.NoDataError Polars.row(df; by_predicate=Polars.Column("m", [false, false, false]))
+  @test_throws Polars.Errors.ComputeError Polars.row(df; by_predicate=Polars.Column("m", [true, false, true]))
+end
+
+@testset "Value tests" begin
+  @test Polars.Value(1)[] === 1
+  @test Polars.Value(Int8(1))[] === Int8(1)
+  @test Polars.Value(1.5f0)[] === 1.5f0
+  @test Polars.Value(true)[] === true
+  @test Polars.Value(nothing)[] === nothing
+  @test Polars.Value(missing)[] === nothing
+  @test Polars.Value("abc")[] == "abc"
+  @test Polars.Value(b"ab")[] == b"ab"
+  @test Polars.Value(Polars.Decimal(125, 2))[] === Polars.Decimal(125, 2)
+  @test Polars.Value(Date(2024, 2, 29))[] == Date(2024, 2, 29)
+  @test Polars.Value(DateTime(2024, 1, 1, 12))[] == DateTime(2024, 1, 1, 12)
+  @test Polars.Value(Time(1, 2, 3))[] == Time(1, 2, 3)
+  @test Polars.Value(Millisecond(5))[] == Millisecond(5)
+  @test Polars.Value([1, 2, 3])[] == [1, 2, 3]
+  @test Polars.Value([[1], [2, 3]])[] == [[1], [2, 3]]
+  @test Polars.Value((a=1, b="x"))[] == (a=1, b="x")
+  @test Polars.dtype(Polars.Value((a=1, b="x"))) == Polars.DataTypes.Struct((a=Polars.DataTypes.Int64(), b=Polars.DataTypes.String()))
+  @test Polars.dtype(Polars.Value([1.0])) == Polars.DataTypes.List(Polars.DataTypes.Float64())
+  @test_throws Polars.PolarsError Polars.Value([1, "a"])
+  @test Polars.Value(-7)[] === -7
+  @test Polars.Value(typemax(UInt64))[] === typemax(UInt64)
+  @test Polars.Value(-Int128(2)^100)[] === -Int128(2)^100
+  @test Polars.Value(-2.25)[] === -2.25
+  @test Polars.dtype(Polars.Value(Int32(1))) == Polars.DataTypes.Int32()
+  @test Polars.Value(Polars.Decimal(-125, 2))[] === Polars.Decimal(-125, 2)
+  @test Polars.Value(Nanosecond(7))[] == Nanosecond(7)
+  @test Polars.Value(Polars.Timestamp(1_500, :μs, "UTC"))[] == Polars.Timestamp(1_500, :μs, "UTC")
+  @test_throws MethodError Polars.Value(UInt128(1))
+  col = Polars.Column("d", [Date(2024, 1, 1), nothing])
+  @test Polars.dtype(col) == Polars.DataTypes.Date()
+  @test isequal(collect(col), [Date(2024, 1, 1), nothing])
+end
+
+@testset "Kernel tests" begin
+  a = Polars.Column("a", [1, 2, 3, 4])
+  b = Polars.Column("b", [4, 3, 2, 1])
+  @test collect(a + b) == [5, 5, 5, 5]
+  @test Polars.name(a + b) == "a"
+  @test collect(a - 1) == [0, 1, 2, 3]
+  @test collect(10 - a) == [9, 8, 7, 6]
+  @test collect(a * 2.0) == [2.0, 4.0, 6.0, 8.0]
+  @test collect(a / 2) == [0.5, 1.0, 1.5, 2.0]
+  @test collect(rem(a, 3)) == [1, 2, 0, 1]
+  @test collect(-a) == [-1, -2, -3, -4]
+  @test collect(a .+ b) == [5, 5, 5, 5]
+  @test collect(a .> 2) == [false, false, true, true]
+  @test collect(a .== b) == [false, false, false, false]
+  @test collect(2 .<= a) == [false, true, true, true]
+  @test collect((a .> 1) & (a .< 4)) == [false, true, true, false]
+  @test collect((a .< 2) | (a .> 3)) == [true, false, false, true]
+  @test isequal(collect(Polars.Column("n", [1, nothing]) + 1), [2, nothing])
+  @test_throws Polars.PolarsError a + Polars.Column("s", ["x", "y", "z", "w"])
+  i32 = Polars.Column("i", Int32[1, 2])
+  f32 = Polars.Column("f", Float32[1.5, 2.5])
+  @test Polars.dtype(i32 + 1) == Polars.DataTypes.Int32()
+  @test collect(i32 + 1) == Int32[2, 3]
+  @test Polars.dtype(i32 .> 1) == Polars.DataTypes.Boolean()
+  @test Polars.dtype(i32 + 1.5) == Polars.DataTypes.Float64()
+  @test Polars.dtype(f32 * 2) == Polars.DataTypes.Float32()
+  @test collect(f32 * 2) == Float32[3.0, 5.0]
+  @test Polars.dtype(-i32) == Polars.DataTypes.Int32()
+  @test Polars.dtype(-f32) == Polars.DataTypes.Float32()
+  @test collect(-f32) == Float32[-1.5, -2.5]
+  @test Polars.dtype(i32 + typemax(Int64)) == Polars.DataTypes.Int64()
+  @test_throws MethodError Polars.Column("t", [DateTime(2024, 1, 1)]) + Day(1)
+  @test sum(a) == 10
+  @test prod(a) == 24
+  @test minimum(a) == 1
+  @test maximum(a) == 4
+  @test any(a .> 3)
+  @test !all(a .> 3)
+  @test Polars.n_unique(Polars.Column("u", [1, 1, 2])) == 2
+  @test Statistics.mean(a) == 2.5
+  @test Statistics.median(a) == 2.5
+  @test Statistics.var(a) ≈ Statistics.var([1, 2, 3, 4])
+  @test Statistics.std(a; corrected=false) ≈ Statistics.std([1, 2, 3, 4]; corrected=false)
+  @test Statistics.quantile(a, 0.5) == 2.5
+  @test Statistics.quantile(a, 0.5; method=:lower) == 2.0
+  @test_throws Polars.Errors.InvalidArgumentError Statistics.quantile(a, 0.5; method=:bogus)
+end
+
+@testset "Cast tests" begin
+  col = Polars.Column("a", [1, 2, 3_000_000_000])
+  @test_throws Polars.Errors.InvalidOperationError Polars.cast(col, Polars.DataTypes.Int32())
+  err = try Polars.cast(col, Polars.DataTypes.Int32()) catch e e end
+  @test occursin("3000000000", Polars.Errors.message(err))
+  @test isequal(collect(Polars.cast(col, Polars.DataTypes.Int32(); strict=false)), Union{Int32, Nothing}[1, 2, nothing])
+  @test collect(Polars.cast(Polars.Column("a", [1, 2]), Polars.DataTypes.Int32())) == Int32[1, 2]
+  dates = Polars.cast(Polars.Column("d", ["2024-01-01", "2024-02-29"]), Polars.DataTypes.Date())
+  @test collect(dates) == [Date(2024, 1, 1), Date(2024, 2, 29)]
+  ns = Polars.cast(Polars.Column("t", [DateTime(2024, 1, 1)]), Polars.DataTypes.DateTime{:ns}(nothing))
+  @test Polars.dtype(ns) == Polars.DataTypes.DateTime{:ns}(nothing)
+  df = Polars.DataFrame([Polars.Column("a", [1, 2]), Polars.Column("b", ["1.5", "x"])])
+  df2 = Polars.cast(df, Dict("a" => Polars.DataTypes.Int32()))
+  @test Polars.dtype(df2["a"]) == Polars.DataTypes.Int32()
+  @test Polars.dtype(df2["b"]) == Polars.DataTypes.String()
+  @test_throws Polars.Errors.InvalidOperationError Polars.cast(df, ["b" => Polars.DataTypes.Float64()])
+  df3 = Polars.cast(df, ["b" => Polars.DataTypes.Float64()]; strict=false)
+  @test isequal(collect(df3["b"]), [1.5, nothing])
+  @test_throws Polars.Errors.ColumnNotFoundError Polars.cast(df, ["c" => Polars.DataTypes.Int32()])
+end
+
+@testset "String namespace tests" begin
+  S = Polars.Strings
+  col = Polars.Column("s", ["apple pie", "Banana", nothing, "a.b"])
+  @test isequal(collect(S.contains(col, "a.")), [false, false, nothing, true])
+  @test isequal(collect(S.contains(col, r"a.")), [true, true, nothing, true])
+  @test isequal(collect(S.contains(col, r"^b"i)), [false, true, nothing, false])
+  @test isequal(collect(S.replace_all(col, r"A"i => "o")), ["opple pie", "Bonono", nothing, "o.b"])
+  @test collect(S.extract(Polars.Column("m", ["x\nab"]), r"^(a)"m)) == ["a"]
+  @test collect(S.extract(Polars.Column("m", ["a\nb"]), r"(a.b)"s)) == ["a\nb"]
+  @test collect(S.extract_all(Polars.Column("m", ["12 34"]), r"\d  \d"x)) == [["12", "34"]]
+  @test isequal(collect(S.starts_with(col, "a")), [true, false, nothing, true])
+  @test isequal(collect(S.ends_with(col, "na")), [false, true, nothing, false])
+  @test Polars.name(S.to_uppercase(col)) == "s"
+  @test isequal(collect(S.to_uppercase(col)), ["APPLE PIE", "BANANA", nothing, "A.B"])
+  @test isequal(collect(S.to_lowercase(col)), ["apple pie", "banana", nothing, "a.b"])
+  @test isequal(collect(S.replace(col, "a" => "o")), ["opple pie", "Bonana", nothing, "o.b"])
+  @test isequal(collect(S.replace_all(col, "a" => "o")), ["opple pie", "Bonono", nothing, "o.b"])
+  @test isequal(collect(S.replace_all(col, r"(a)(n)" => "\$2\$1")), ["apple pie", "Bnanaa", nothing, "a.b"])
+  @test isequal(collect(S.split(col, " ")), [["apple", "pie"], ["Banana"], nothing, ["a.b"]])
+  @test isequal(collect(S.len_chars(col)), UInt32[9, 6, nothing, 3])
+  @test collect(S.len_bytes(Polars.Column("u", ["é"]))) == UInt32[2]
+  @test isequal(collect(S.slice(col, 2, 3)), ["ppl", "ana", nothing, ".b"])
+  @test isequal(collect(S.slice(col, -2)), ["ie", "na", nothing, ".b"])
+  @test_throws ArgumentError S.slice(col, 0)
+  ids = Polars.Column("id", ["id-12 x-3", "id-7"])
+  @test collect(S.extract(ids, r"id-(\d+)")) == ["12", "7"]
+  @test collect(S.extract(ids, r"id-(\d+)", 0)) == ["id-12", "id-7"]
+  @test collect(S.extract_all(ids, r"\d+")) == [["12", "3"], ["7"]]
+  dates = Polars.Column("d", ["2024-01-31", "2023-12-01"])
+  @test collect(S.extract_groups(dates, r"(?<y>\d{4})-(?<m>\d{2})")) == [(y="2024", m="01"), (y="2023", m="12")]
+  @test collect(S.strip_chars(Polars.Column("p", ["  x  ", "\ty"]))) == ["x", "y"]
+  @test collect(S.strip_chars(Polars.Column("p", ["--x--"]), "-"; side=:end)) == ["--x"]
+  @test_throws Polars.Errors.InvalidArgumentError S.strip_chars(col; side=:middle)
+  @test collect(S.zfill(Polars.Column("z", ["7", "-12"]), 4)) == ["0007", "-012"]
+  @test collect(S.strptime(dates, Polars.DataTypes.Date())) == [Date(2024, 1, 31), Date(2023, 12, 1)]
+  @test collect(S.strptime(Polars.Column("d", ["31/01/2024 10:30"]), Polars.DataTypes.DateTime{:ms}(nothing), "%d/%m/%Y %H:%M")) == [DateTime(2024, 1, 31, 10, 30)]
+  @test_throws Polars.PolarsError S.strptime(Polars.Column("d", ["nope"]), Polars.DataTypes.Date(), "%Y-%m-%d")
+  @test isequal(collect(S.strptime(Polars.Column("d", ["nope"]), Polars.DataTypes.Date(), "%Y-%m-%d"; strict=false)), [nothing])
+  json = Polars.Column("j", ["{\"a\": 1, \"b\": \"x\"}", "{\"a\": 2, \"b\": null}"])
+  @test isequal(collect(S.json_decode(json)), [(a=1, b="x"), (a=2, b=nothing)])
+  decoded = S.json_decode(json, Polars.DataTypes.Struct((a=Polars.DataTypes.Int32(), b=Polars.DataTypes.String())))
+  @test Polars.dtype(decoded) == Polars.DataTypes.Struct((a=Polars.DataTypes.Int32(), b=Polars.DataTypes.String()))
+  @test_throws Polars.Errors.SchemaMismatchError S.to_uppercase(Polars.Column("n", [1, 2]))
+end
+
+@testset "Temporal namespace tests" begin
+  T = Polars.Temporal
+  dts = Polars.Column("t", [DateTime(2024, 2, 29, 13, 47, 31, 250), nothing])
+  @test isequal(collect(T.year(dts)), Int32[2024, nothing])
+  @test isequal(collect(T.month(dts)), Int8[2, nothing])
+  @test isequal(collect(T.day(dts)), Int8[29, nothing])
+  @test isequal(collect(T.hour(dts)), Int8[13, nothing])
+  @test isequal(collect(T.minute(dts)), Int8[47, nothing])
+  @test isequal(collect(T.second(dts)), Int8[31, nothing])
+  @test isequal(collect(T.millisecond(dts)), Int32[250, nothing])
+  @test isequal(collect(T.weekday(dts)), Int8[4, nothing])
+  @test isequal(collect(T.quarter(dts)), Int8[1, nothing])
+  @test isequal(collect(T.is_leap_year(dts)), [true, nothing])
+  @test isequal(collect(T.date(dts)), [Date(2024, 2, 29), nothing])
+  @test isequal(collect(T.time(dts)), [Time(13, 47, 31, 250), nothing])
+  @test Polars.name(T.year(dts)) == "t"
+  @test isequal(collect(T.truncate(dts, "1h")), [DateTime(2024, 2, 29, 13), nothing])
+  @test isequal(collect(T.truncate(dts, "15m")), [DateTime(2024, 2, 29, 13, 45), nothing])
+  @test isequal(collect(round(dts, "1h")), [DateTime(2024, 2, 29, 14), nothing])
+  @test isequal(collect(T.offset_by(dts, "1mo")), [DateTime(2024, 3, 29, 13, 47, 31, 250), nothing])
+  @test isequal(collect(T.offset_by(dts, "-1y")), [DateTime(2023, 2, 28, 13, 47, 31, 250), nothing])
+  @test isequal(collect(T.strftime(dts, "%Y/%m/%d %H:%M")), ["2024/02/29 13:47", nothing])
+  epoch = Polars.Column("e", [DateTime(1970, 1, 2)])
+  @test collect(T.epoch(epoch)) == [86400]
+  @test collect(T.epoch(epoch, :ms)) == [86_400_000]
+  @test collect(T.epoch(epoch, :d)) == Int32[1]
+  @test collect(T.from_epoch(Polars.Column("e", [86400]))) == [DateTime(1970, 1, 2)]
+  @test collect(T.from_epoch(Polars.Column("e", [1]), :d)) == [Date(1970, 1, 2)]
+  @test_throws Polars.Errors.InvalidArgumentError T.epoch(epoch, :weeks)
+  zoned = T.replace_time_zone(epoch, "UTC")
+  @test collect(T.hour(T.convert_time_zone(zoned, "Asia/Shanghai"))) == Int8[8]
+  durations = Polars.Column("d", [Millisecond(90 * 60_000), Millisecond(1500)])
+  @test collect(T.total_hours(durations)) == [1, 0]
+  @test collect(T.total_minutes(durations)) == [90, 0]
+  @test collect(T.total(durations, :milliseconds)) == [5_400_000, 1500]
+  @test_throws Polars.Errors.InvalidOperationError T.year(Polars.Column("n", [1]))
+end
+
+@testset "List namespace tests" begin
+  L = Polars.Lists
+  df = Polars.read_parquet("test.parquet")
+  col = df["col_list_int32"]
+  arr = df["col_array_float64"]
+  @test collect(L.lengths(col)) == UInt32[2, 2, 1]
+  @test collect(L.lengths(arr)) == UInt32[1, 1, 1]
+  @test collect(L.get(col, 1)) == Int32[1, 3, 5]
+  @test isequal(collect(L.get(col, 2; null_on_oob=true)), Int32[2, 4, nothing])
+  @test collect(L.get(col, -1)) == Int32[2, 4, 5]
+  @test_throws Polars.PolarsError L.get(col, 2)
+  @test collect(L.slice(col, 2)) == [Int32[2], Int32[4], Int32[]]
+  @test collect(L.contains(col, Int32(4))) == [false, true, false]
+  @test collect(L.sum(col)) == Int32[3, 7, 5]
+  @test collect(L.mean(col)) == [1.5, 3.5, 5.0]
+  @test collect(L.minimum(col)) == Int32[1, 3, 5]
+  @test collect(L.maximum(arr)) == [1.0, 3.0, 5.0]
+  @test collect(L.last(col)) == Int32[2, 4, 5]
+  @test collect(L.sort(col; rev=true)) == [Int32[2, 1], Int32[4, 3], Int32[5]]
+  words = Polars.Strings.split(Polars.Column("w", ["b a b", "c"]), " ")
+  @test collect(L.unique(words; maintain_order=true)) == [["b", "a"], ["c"]]
+  @test collect(L.n_unique(words)) == UInt32[2, 1]
+  @test collect(L.join(words, "-")) == ["b-a-b", "c"]
+  @test collect(L.map_values(v -> v * 2, col)) == [Int32[2, 4], Int32[6, 8], Int32[10]]
+  @test collect(L.map_values(v -> v .> 2, col)) == [[false, false], [true, true], [true]]
+  doubled = L.map_values(v -> v * 2.0, arr)
+  @test Polars.dtype(doubled) == Polars.dtype(arr)
+  @test collect(doubled) == [[2.0], [6.0], [10.0]]
+  @test_throws Polars.Errors.ComputeError L.map_values(v -> Polars.Column("x", [1]), col)
+  E = Polars.Exprs
+  @test collect(L.eval(col, E.element() * 2)) == [[2, 4], [6, 8], [10]]
+  @test collect(L.eval(col, E.rank(E.element(); rev=true))) == [[2.0, 1.0], [2.0, 1.0], [1.0]]
+  @test collect(L.eval(col, E.element() - first(E.element()))) == [[0, 1], [0, 1], [0]]
+  @test isequal(collect(L.eval(Polars.Column("n", [[1, nothing], nothing, Int[]]), E.element() * 2)), [[2, nothing], nothing, Int[]])
+  @test_throws Polars.Errors.ComputeError L.eval(col, E.col("x"))
+  arr2 = Polars.cast(Polars.Column("a2", [[1.0, 2.0], [3.0, 4.0]]), Polars.DataTypes.Array(Polars.DataTypes.Float64(), 2))
+  @test Polars.dtype(arr2) == Polars.DataTypes.Array(Polars.DataTypes.Float64(), 2)
+  @test collect(L.sum(arr2)) == [3.0, 7.0]
+  @test collect(L.get(arr2, 2)) == [2.0, 4.0]
+  @test collect(L.eval(arr2, E.element() * 2.0)) == [[2.0, 4.0], [6.0, 8.0]]
+  scaled = L.map_values(v -> v * 2.0, arr2)
+  @test Polars.dtype(scaled) == Polars.dtype(arr2)
+  @test collect(scaled) == [[2.0, 4.0], [6.0, 8.0]]
+  @test_throws ArgumentError L.get(col, 0)
+  @test_throws ArgumentError L.slice(col, 0)
+  @test_throws Polars.Errors.InvalidOperationError L.sum(Polars.Column("n", [1]))
+  exploded = Polars.explode(Polars.DataFrame([Polars.Column("k", ["x", "y"]), words]), "w")
+  @test collect(exploded["k"]) == ["x", "x", "x", "y"]
+  @test collect(exploded["w"]) == ["b", "a", "b", "c"]
+  imploded = Polars.implode(exploded)
+  @test Polars.height(imploded) == 1
+  @test imploded["w", 1] == ["b", "a", "b", "c"]
+  @test collect(Polars.implode(Polars.Column("a", [1, 2]))) == [[1, 2]]
+end
+
+@testset "Window tests" begin
+  E = Polars.Exprs
+  col, lit = Polars.col, Polars.lit
+  df = Polars.DataFrame([
+    Polars.Column("id", ["a", "b", "a", "b", "a"]),
+    Polars.Column("t", [3, 1, 1, 2, 2]),
+    Polars.Column("x", [10, 20, 30, 40, 50]),
+  ])
+  @test occursin("col(\"x\")", sprint(show, col("x") + 1))
+  out = Polars.select(df, E.alias(col("x") * 2, "x2"), E.alias(col("x") .> 25, "big"))
+  @test collect(out["x2"]) == [20, 40, 60, 80, 100]
+  @test collect(out["big"]) == [false, false, true, true, true]
+  @test collect(Polars.select(df, sum(col("x")))["x"]) == [150]
+  out = Polars.with_columns(df, E.over(sum(col("x")), "id"))
+  @test collect(out["x"]) == [90, 60, 90, 60, 90]
+  @test collect(out["id"]) == ["a", "b", "a", "b", "a"]
+  # rows of id "a" in order of t are x = 30, 50, 10
+  out = Polars.with_columns(df,
+    E.alias(E.cum_sum(col("x")) |> E.over("id"; order_by="t"), "cs"),
+    E.alias(E.cum_max(col("x")) |> E.over("id"), "cm"),
+    E.alias(E.cum_count(col("x")) |> E.over("id"), "cc"),
+    E.alias(E.lag(col("x")) |> E.over("id"; order_by="t"), "prev"),
+    E.alias(diff(col("x")) |> E.over("id"; order_by=col("t")), "d"),
+    E.alias(E.pct_change(col("x")) |> E.over("id"), "pct"),
+    E.alias(E.rank(col("x"); method=:dense, rev=true) |> E.over("id"), "r"),
+  )
+  @test collect(out["cs"]) == [90, 20, 30, 60, 80]
+  @test collect(out["cm"]) == [10, 20, 30, 40, 50]
+  @test collect(out["cc"]) == UInt32[1, 1, 2, 2, 3]
+  @test isequal(collect(out["prev"]), [50, nothing, nothing, 20, 30])
+  @test isequal(collect(out["d"]), [-40, nothing, nothing, 20, 20])
+  @test isequal(collect(out["pct"]), [nothing, nothing, 2.0, 1.0, 2 / 3])
+  @test collect(out["r"]) == UInt32[3, 2, 2, 1, 1]
+  out = Polars.with_columns(df, E.alias(E.cum_sum(col("x")) |> E.over((col("id"),); order_by=col("t")), "cs"))
+  @test collect(out["cs"]) == [90, 20, 30, 60, 80]
+  out = Polars.with_columns(df, E.alias(sum(col("x")) |> E.over([col("id"), "t"]), "s"))
+  @test collect(out["s"]) == [10, 20, 30, 40, 50]
+  @test_throws ArgumentError E.over(col("id"))(sum(col("x")))
+  narrow = Polars.DataFrame([Polars.Column("i", Int32[1, 2]), Polars.Column("f", Float32[0.5, 1.5])])
+  out = Polars.select(narrow, E.alias(col("i") + 1, "i1"), E.alias(-col("i"), "ni"), E.alias(col("f") * 2, "f2"),
+                      E.alias(-col("f"), "nf"), E.alias(col("i") + 1.5, "if"))
+  @test Polars.dtype(out["i1"]) == Polars.DataTypes.Int32()
+  @test collect(out["i1"]) == Int32[2, 3]
+  @test Polars.dtype(out["ni"]) == Polars.DataTypes.Int32()
+  @test collect(out["ni"]) == Int32[-1, -2]
+  @test Polars.dtype(out["f2"]) == Polars.DataTypes.Float32()
+  @test collect(out["f2"]) == Float32[1.0, 3.0]
+  @test Polars.dtype(out["nf"]) == Polars.DataTypes.Float32()
+  @test Polars.dtype(out["if"]) == Polars.DataTypes.Float64()
+  @test Polars.dtype(Polars.select(narrow, E.lit(1))["literal"]) == Polars.DataTypes.Int64()
+  @test isequal(collect(Polars.select(df, E.shift(col("x"), 2))["x"]), [nothing, nothing, 10, 20, 30])
+  @test isequal(collect(Polars.select(df, E.lead(col("x")))["x"]), [20, 30, 40, 50, nothing])
+  @test_throws Polars.Errors.ColumnNotFoundError Polars.select(df, col("nope"))
+  @test_throws Polars.Errors.InvalidArgumentError E.rank(col("x"); method=:bogus)
+end
+
+@testset "Rolling and dynamic group tests" begin
+  E = Polars.Exprs
+  col = Polars.col
+  @test Polars.interval("1h30m") == "1h30m"
+  @test Polars.interval(Hour(2)) == "2h"
+  @test Polars.interval(Microsecond(5)) == "5μs"
+  @test Polars.interval(Day(1) + Minute(15)) == "1d15m"
+  @test Polars.interval(Hour(-1)) == "-1h"
+  @test Polars.interval(-Day(1) - Minute(15)) == "-1d15m"
+  @test_throws ArgumentError Polars.interval(Hour(1) - Minute(30))
+  @test Polars._negate("-1h") == "1h"
+  @test Polars._negate(Polars.interval(Hour(2))) == "-2h"
+  times = [DateTime(2024, 1, 1, 0), DateTime(2024, 1, 1, 1), DateTime(2024, 1, 1, 3), DateTime(2024, 1, 2, 0)]
+  df = Polars.DataFrame([Polars.Column("t", times), Polars.Column("x", [1, 2, 3, 4])])
+  out = Polars.rolling(df, "t", E.alias(sum(col("x")), "s"), E.alias(E.len(col("x")), "n"); period="2h")
+  @test collect(out["t"]) == times
+  @test collect(out["s"]) == [1, 3, 3, 4]
+  @test collect(out["n"]) == UInt32[1, 2, 1, 1]
+  @test collect(Polars.rolling(df, "t", sum(col("x")); period=Hour(2), closed=:both)["x"]) == [1, 3, 5, 4]
+  out = Polars.group_by_dynamic(df, "t", E.alias(sum(col("x")), "s"); every="1d")
+  @test collect(out["t"]) == [DateTime(2024, 1, 1), DateTime(2024, 1, 2)]
+  @test collect(out["s"]) == [6, 4]
+  out = Polars.group_by_dynamic(df, "t", sum(col("x")); every=Hour(2), label=:right)
+  @test collect(out["t"]) == [DateTime(2024, 1, 1, 2), DateTime(2024, 1, 1, 4), DateTime(2024, 1, 2, 2)]
+  @test collect(out["x"]) == [3, 3, 4]
+  grouped = Polars.DataFrame([Polars.Column("g", ["a", "a", "b", "b"]), Polars.Column("i", [1, 2, 1, 2]), Polars.Column("x", [1, 2, 3, 4])])
+  out = Polars.rolling(grouped, "i", sum(col("x")); period="2i", group_by=["g"])
+  @test collect(out["g"]) == ["a", "a", "b", "b"]
+  @test collect(out["x"]) == [1, 3, 3, 7]
+  @test_throws Polars.Errors.InvalidArgumentError Polars.rolling(df, "t", sum(col("x")); period="2h", closed=:middle)
+  @test_throws Polars.PolarsError Polars.rolling(df, "t", sum(col("x")); period="two hours")
+end
+
+@testset "Pivot tests" begin
+  long = Polars.DataFrame([Polars.Column("id", [1, 1, 2, 2, 2]), Polars.Column("k", ["a", "b", "a", "b", "b"]), Polars.Column("v", [1.0, 2.0, 3.0, 4.0, 5.0])])
+  wide = Polars.pivot(long; on="k", index="id", values="v", aggregate_function=:sum)
+  @test size(wide) == 2
+  @test collect(wide["id"]) == [1, 2]
+  @test collect(wide["a"]) == [1.0, 3.0]
+  @test collect(wide["b"]) == [2.0, 9.0]
+  @test collect(Polars.pivot(long; on="k", index="id", aggregate_function=:len)["b"]) == UInt32[1, 2]
+  @test collect(Polars.pivot(long; on="k", index="id", aggregate_function=:last)["b"]) == [2.0, 5.0]
+  @test_throws Polars.Errors.ComputeError Polars.pivot(long; on="k", index="id", values="v")
+  @test_throws Polars.Errors.InvalidArgumentError Polars.pivot(long; on="k", index="id", aggregate_function=:mode)
+  partial = Polars.DataFrame([Polars.Column("id", [1, 2]), Polars.Column("k", ["a", "b"]), Polars.Column("v", [1, 2])])
+  wide = Polars.pivot(partial; on="k", index="id")
+  @test isequal(collect(wide["a"]), [1, nothing])
+  @test isequal(collect(wide["b"]), [nothing, 2])
+  wide = Polars.DataFrame([Polars.Column("id", [1, 2]), Polars.Column("x", [1, 2]), Polars.Column("y", [3, 4])])
+  long = Polars.unpivot(wide; index="id")
+  @test size(long) == 4
+  @test collect(long["id"]) == [1, 2, 1, 2]
+  @test collect(long["variable"]) == ["x", "x", "y", "y"]
+  @test collect(long["value"]) == [1, 2, 3, 4]
+  long = Polars.unpivot(wide; on=["y"], index="id", variable_name="name", value_name="n")
+  @test collect(long["name"]) == ["y", "y"]
+  @test collect(long["n"]) == [3, 4]
+  @test_throws Polars.PolarsError Polars.unpivot(wide; on="z", index="id")
+end
+
+@testset "Unique tests" begin
+  df = Polars.DataFrame([Polars.Column("k", ["a", "b", "a", "c", "a"]), Polars.Column("v", [1, 2, 1, 3, 4])])
+  @test size(unique(df)) == 4
+  out = Polars.unique_stable(df)
+  @test collect(out["k"]) == ["a", "b", "c", "a"]
+  @test collect(out["v"]) == [1, 2, 3, 4]
+  @test collect(Polars.unique_stable(df; subset="k")["v"]) == [1, 2, 3]
+  @test collect(Polars.unique_stable(df; subset="k", keep=:last)["v"]) == [2, 3, 4]
+  @test collect(Polars.unique_stable(df; subset=["k"], keep=:none)["k"]) == ["b", "c"]
+  @test Polars.n_unique(df) == 4
+  @test Polars.n_unique(df; subset="k") == 3
+  @test Polars.n_unique(df; subset=["k", "v"]) == 4
+  @test Polars.n_unique(Polars.DataFrame([Polars.Column("n", [nothing, nothing, 1])])) == 2
+  @test Polars.n_unique(Polars.DataFrame([Polars.Column("e", Int[]), Polars.Column("f", Int[])])) == 0
+  @test collect(unique(df; subset="k")["v"]) == collect(Polars.unique_stable(df; subset="k")["v"])
+  @test collect(Polars.is_duplicated(df)) == [true, false, true, false, false]
+  @test collect(Polars.is_unique(df; subset="k")) == [false, true, false, true, false]
+  @test collect(Polars.is_duplicated(df["k"])) == [true, false, true, false, true]
+  @test collect(Polars.is_unique(df["v"])) == [false, true, false, true, true]
+  @test_throws Polars.Errors.InvalidArgumentError Polars.unique_stable(df; keep=:middle)
+  @test_throws Polars.Errors.ColumnNotFoundError Polars.is_unique(df; subset="z")
+  counts = Polars.value_counts(df["k"]; sort=true)
+  @test collect(counts["k"])[1] == "a"
+  @test collect(counts["count"])[1] == 3
+  @test sum(collect(counts["count"])) == 5
+  counts = Polars.value_counts(df["k"]; sort=true, normalize=true)
+  @test collect(counts["proportion"])[1] ≈ 0.6
+  @test_throws Polars.Errors.DuplicateError Polars.value_counts(df["k"]; name="k")
+end
+
+@testset "Null handling tests" begin
+  col = Polars.Column("x", [1, nothing, nothing, 4, nothing])
+  @test collect(Polars.fill_null(col, 0)) == [1, 0, 0, 4, 0]
+  @test collect(Polars.fill_null(col, 2.5)) == [1.0, 2.5, 2.5, 4.0, 2.5]
+  @test collect(Polars.fill_null(col; strategy=:forward)) == [1, 1, 1, 4, 4]
+  @test isequal(collect(Polars.fill_null(col; strategy=:forward, limit=1)), [1, 1, nothing, 4, 4])
+  @test isequal(collect(Polars.fill_null(col; strategy=:backward)), [1, 4, 4, 4, nothing])
+  @test collect(Polars.fill_null(col; strategy=:max)) == [1, 4, 4, 4, 4]
+  @test collect(Polars.fill_null(col; strategy=:zero)) == [1, 0, 0, 4, 0]
+  @test_throws Polars.Errors.InvalidArgumentError Polars.fill_null(col; strategy=:sideways)
+  @test isequal(collect(Polars.interpolate(col)), [1, 2, 3, 4, nothing])
+  @test isequal(collect(Polars.interpolate(col; method=:nearest)), [1, 1, 4, 4, nothing])
+  @test_throws Polars.Errors.InvalidArgumentError Polars.interpolate(col; method=:cubic)
+  floats = Polars.Column("f", [1.0, NaN, nothing])
+  @test isequal(collect(Polars.is_nan(floats)), [false, true, nothing])
+  @test isequal(collect(Polars.fill_nan(floats, 0.0)), [1.0, 0.0, nothing])
+  @test_throws Polars.PolarsError Polars.is_nan(col)
+  df = Polars.DataFrame([Polars.Column("a", [1, nothing, 3]), Polars.Column("b", ["x", "y", nothing])])
+  @test collect(Polars.drop_nulls(df)["a"]) == [1]
+  @test isequal(collect(Polars.drop_nulls(df; subset="a")["b"]), ["x", nothing])
+  @test size(Polars.drop_nulls(df; subset=["b"])) == 2
+end
+
+@testset "SQL tests" begin
+  S = Polars.SQL
+  sales = Polars.DataFrame([Polars.Column("k", ["a", "b", "a"]), Polars.Column("v", [1, 2, 3])])
+  names = Polars.DataFrame([Polars.Column("k", ["a", "b"]), Polars.Column("name", ["alpha", "beta"])])
+  ctx = Polars.SQLContext("sales" => sales)
+  @test S.tables(ctx) == ["sales"]
+  out = S.execute(ctx, "SELECT k, SUM(v) AS v FROM sales GROUP BY k ORDER BY k")
+  @test collect(out["k"]) == ["a", "b"]
+  @test collect(out["v"]) == [4, 2]
+  S.register(ctx, "names", names)
+  @test S.tables(ctx) == ["names", "sales"]
+  out = S.execute(ctx, "SELECT n.name, s.v FROM sales s JOIN names n ON s.k = n.k WHERE s.v > 1 ORDER BY s.v")
+  @test collect(out["name"]) == ["beta", "alpha"]
+  @test collect(out["v"]) == [2, 3]
+  S.unregister(ctx, "names")
+  @test S.tables(ctx) == ["sales"]
+  @test_throws Polars.PolarsError S.execute(ctx, "SELECT * FROM names")
+  @test_throws Polars.PolarsError S.execute(ctx, "SELEKT 1")
+  @test isempty(S.tables(Polars.SQLContext()))
+end
+
+@testset "Lazy and streaming tests" begin
+  col = Polars.col
+  df = Polars.DataFrame([Polars.Column("k", ["a", "b", "a", "c"]), Polars.Column("v", [1, 2, 3, 4])])
+  mktempdir() do dir
+    path = joinpath(dir, "in.parquet")
+    Polars.write_parquet(df, path)
+    lf = Polars.scan_parquet(path)
+    @test collect(collect(lf)["v"]) == [1, 2, 3, 4]
+    query = Polars.group_by(Polars.filter(lf, col("v") .> 1), "k", sum(col("v")); maintain_order=true)
+    @test occursin("FILTER", uppercase(Polars.explain(query)))
+    for streaming in (false, true)
+      out = collect(query; streaming)
+      @test collect(out["k"]) == ["b", "a", "c"]
+      @test collect(out["v"]) == [2, 3, 4]
+    end
+    out = collect(first(sort(Polars.with_columns(lf, Polars.Exprs.alias(col("v") * 10, "w")), "v"; rev=true), 2))
+    @test collect(out["w"]) == [40, 30]
+    Polars.sink_parquet(Polars.filter(lf, col("k") .== "a"), joinpath(dir, "a.parquet"))
+    @test collect(Polars.read_parquet(joinpath(dir, "a.parquet"))["v"]) == [1, 3]
+    Polars.sink_ipc(Polars.select(lf, col("v")), joinpath(dir, "v.ipc"))
+    @test collect(collect(Polars.scan_ipc(joinpath(dir, "v.ipc")))["v"]) == [1, 2, 3, 4]
+    Polars.sink_csv(Polars.lazy(df), joinpath(dir, "all.csv"))
+    out = collect(Polars.scan_csv(joinpath(dir, "all.csv")); streaming=true)
+    @test collect(out["k"]) == ["a", "b", "a", "c"]
+    @test collect(out["v"]) == [1, 2, 3, 4]
+    ctx = Polars.SQLContext("t" => lf)
+    @test collect(Polars.SQL.execute(ctx, "SELECT SUM(v) AS s FROM t WHERE k = 'a'")["s"]) == [4]
+    @test_throws Polars.PolarsError collect(Polars.scan_parquet(joinpath(dir, "missing.parquet")))
+    @test_throws Polars.Errors.ColumnNotFoundError collect(Polars.select(lf, col("z")))
+  end
+end
+
+@testset "Hive partitioning tests" begin
+  col = Polars.col
+  df = Polars.DataFrame([Polars.Column("year", [2023, 2024, 2023, 2024]), Polars.Column("k", ["a", "a", "b", "b"]),
+                         Polars.Column("v", [1, 2, 3, 4])])
+  mktempdir() do dir
+    root = joinpath(dir, "data")
+    Polars.write_parquet(df, root; partition_by=["year", "k"])
+    @test isfile(joinpath(root, "year=2023", "k=a", "00000000.parquet"))
+    out = collect(sort(Polars.scan_parquet(root), "v"))
+    @test collect(out["year"]) == [2023, 2024, 2023, 2024]
+    @test collect(out["k"]) == ["a", "a", "b", "b"]
+    out = Polars.read_parquet(root)
+    @test collect(out["year"]) == [2023, 2023, 2024, 2024]
+    @test collect(out["k"]) == ["a", "b", "a", "b"]
+    @test collect(out["v"]) == [1, 3, 2, 4]
+    for out in (Polars.read_parquet(root; how=:vertical_relaxed), Polars.read_parquet(joinpath(root, "*", "*", "*.parquet")),
+                Polars.read_files([root]; format=:parquet))
+      @test collect(out["year"]) == [2023, 2023, 2024, 2024]
+      @test collect(out["k"]) == ["a", "b", "a", "b"]
+    end
+    lf = Polars.filter(Polars.scan_parquet(root; include_file_paths="path"), col("year") .== 2024)
+    # the partition predicate is pushed into the scan instead of filtering after it
+    @test occursin("FILTER", Polars.explain(lf; optimized=false))
+    plan = Polars.explain(lf)
+    @test !occursin("FILTER", plan)
+    @test occursin(r"SELECTION: .*col\(\"year\"\)", plan)
+    out = collect(sort(lf, "v"))
+    @test collect(out["v"]) == [2, 4]
+    @test all(p -> occursin("year=2024", p), collect(out["path"]))
+    Polars.write_parquet(df, joinpath(dir, "by_k"); partition_by="k")
+    out = collect(Polars.scan_parquet(joinpath(dir, "by_k", "k=b", "00000000.parquet"); hive_partitioning=true))
+    @test collect(out["k"]) == ["b", "b"]
+    @test_throws Polars.Errors.ColumnNotFoundError Polars.write_parquet(df, joinpath(dir, "bad"); partition_by="z")
+    Polars.write_parquet(df, joinpath(dir, "split"); partition_by="k", chunk_size=1)
+    @test length(readdir(joinpath(dir, "split", "k=b"))) == 2
+    @test collect(Polars.read_parquet(joinpath(dir, "split"))["v"]) == [1, 2, 3, 4]
+    @test_throws Polars.Errors.InvalidOperationError Polars.write_parquet(df, joinpath(dir, "none"); partition_by="k", chunk_size=0)
+  end
+end
+
+@testset "Multi-file read tests" begin
+  a = Polars.DataFrame([Polars.Column("k", ["a", "b"]), Polars.Column("v", [1, 2])])
+  b = Polars.DataFrame([Polars.Column("k", ["c"]), Polars.Column("v", [3.5])])
+  c = Polars.DataFrame([Polars.Column("k", ["d"]), Polars.Column("w", [true])])
+  mktempdir() do dir
+    mkdir(joinpath(dir, "same"))
+    Polars.write_parquet(a, joinpath(dir, "same", "1.parquet"))
+    Polars.write_parquet(a, joinpath(dir, "same", "2.parquet"))
+    out = Polars.read_parquet(joinpath(dir, "same", "*.parquet"))
+    @test collect(out["v"]) == [1, 2, 1, 2]
+    out = Polars.read_parquet(joinpath(dir, "same"); include_file_paths="file")
+    @test basename.(collect(out["file"])) == ["1.parquet", "1.parquet", "2.parquet", "2.parquet"]
+    Polars.write_parquet(b, joinpath(dir, "b.parquet"))
+    Polars.write_parquet(c, joinpath(dir, "c.parquet"))
+    paths = [joinpath(dir, "same", "1.parquet"), joinpath(dir, "b.parquet")]
+    @test_throws Polars.PolarsError Polars.read_parquet(paths)
+    out = Polars.read_parquet(paths; how=:vertical_relaxed)
+    @test collect(out["v"]) == [1.0, 2.0, 3.5]
+    out = Polars.read_parquet([paths[1], joinpath(dir, "c.parquet")]; how=:diagonal)
+    @test collect(out["k"]) == ["a", "b", "d"]
+    @test isequal(collect(out["w"]), [nothing, nothing, true])
+    Polars.sink_csv(Polars.lazy(a), joinpath(dir, "a.csv"))
+    Polars.sink_ipc(Polars.lazy(a), joinpath(dir, "a.ipc"))
+    @test collect(Polars.read_csv([joinpath(dir, "a.csv"), joinpath(dir, "a.csv")])["k"]) == ["a", "b", "a", "b"]
+    @test collect(Polars.read_ipc(joinpath(dir, "*.ipc"))["v"]) == [1, 2]
+    @test_throws Polars.Errors.InvalidArgumentError Polars.read_files(joinpath(dir, "a.csv"); format=:json)
+    @test_throws Polars.PolarsError Polars.read_csv(joinpath(dir, "*.json"))
+  end
 end
 
 @testset "DataType roundtrip tests" begin
@@ -113,12 +862,15 @@ end
   test_roundtrip(:Int16)
   test_roundtrip(:Int32)
   test_roundtrip(:Int64)
+  test_roundtrip(:Int128)
   test_roundtrip(:UInt8)
   test_roundtrip(:UInt16)
   test_roundtrip(:UInt32)
   test_roundtrip(:UInt64)
   test_roundtrip(:Float32)
   test_roundtrip(:Float64)
+  test_roundtrip(:String)
+  test_roundtrip(:Binary)
   test_roundtrip(:Decimal, precision=9, scale=2)
   test_roundtrip(:Datetime, time_unit=:μs, time_zone=nothing)
   test_roundtrip(:Date)
@@ -126,4 +878,7 @@ end
   test_roundtrip(:Duration, time_unit=:μs)
   test_roundtrip(:List, inner=Polars.DataTypes.Int32())
   test_roundtrip(:Array, inner=Polars.DataTypes.Float64(), size=3)
+  test_roundtrip(:Struct, fields=(a=Polars.DataTypes.Int64(), b=Polars.DataTypes.Boolean()))
+  test_roundtrip(:Categorical, name="", namespace="", physical=:u32)
+  test_roundtrip(:Enum, categories=["lo", "mid", "hi"])
 end